@@ -0,0 +1,98 @@
+//! Decode throughput.
+//!
+//! Run with `cargo bench --bench decode`. Compares, on a 1M-word buffer, the crate's
+//! table-driven label reversal against the same decode written with `u8::reverse_bits`,
+//! and a full `decode` against a lazy `WordView` when only the label and data are read.
+//! On 100k-word batches it compares `decode_many`/`decode_many_into` against a push loop.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use arinc429::{
+    check_parity, decode, decode_many, decode_many_into, encode, ArincWord, Label, Ssm, WordView,
+};
+
+const WORDS: usize = 1_000_000;
+const BATCH: usize = 100_000;
+const ROUNDS: u32 = 10;
+
+/// The previous decode path: per-word `reverse_bits` on the label byte.
+fn decode_reverse_bits(word: u32) -> Option<ArincWord> {
+    if !check_parity(word) {
+        return None;
+    }
+    let label = Label::from_u8((word as u8).reverse_bits());
+    let (sdi, data) = if label.sdi_is_data() {
+        (0, (word >> 8) & 0x1F_FFFF)
+    } else {
+        (((word >> 8) & 0x3) as u8, (word >> 10) & 0x7FFFF)
+    };
+    Some(ArincWord {
+        label,
+        sdi,
+        data,
+        ssm: Ssm::from_u8(((word >> 29) & 0x3) as u8),
+    })
+}
+
+fn bench(name: &str, words: &[u32], f: impl Fn(u32) -> bool) {
+    bench_batch(name, words, |words| {
+        words.iter().filter(|&&w| f(black_box(w))).count()
+    });
+}
+
+fn bench_batch(name: &str, words: &[u32], mut f: impl FnMut(&[u32]) -> usize) {
+    let mut best = Duration::MAX;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        black_box(f(black_box(words)));
+        best = best.min(start.elapsed());
+    }
+    let rate = words.len() as f64 / best.as_secs_f64() / 1e6;
+    println!(
+        "{:<24} {:>10.3?} / {} words  ({:.1} Mwords/s)",
+        name,
+        best,
+        words.len(),
+        rate
+    );
+}
+
+fn main() {
+    let words: Vec<u32> = (0..WORDS as u32)
+        .map(|i| encode((i % 256) as u8, (i % 4) as u8, i & 0x7FFFF, 3).unwrap())
+        .collect();
+
+    for &w in &words {
+        assert_eq!(decode(w).ok(), decode_reverse_bits(w));
+    }
+
+    bench("decode (reverse_bits)", &words, |w| {
+        decode_reverse_bits(w).is_some()
+    });
+    bench("decode (lookup table)", &words, |w| decode(w).is_ok());
+
+    // Select ground speed words above 250 kt.
+    bench("decode (label filter)", &words, |w| {
+        decode(w).is_ok_and(|d| d.label == Label::GroundSpeed && d.data > 2000)
+    });
+    bench("WordView (label filter)", &words, |w| {
+        WordView::from_raw_checked(w)
+            .is_ok_and(|v| v.label() == Label::GroundSpeed && v.data() > 2000)
+    });
+
+    let batch = &words[..BATCH];
+    bench_batch("push loop", batch, |words| {
+        let mut out = Vec::new();
+        for &w in words {
+            out.push(decode(w));
+        }
+        out.len()
+    });
+    bench_batch("decode_many", batch, |words| decode_many(words).len());
+    let mut reused = Vec::new();
+    bench_batch("decode_many_into", batch, |words| {
+        decode_many_into(words, &mut reused);
+        reused.len()
+    });
+}
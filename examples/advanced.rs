@@ -1,4 +1,4 @@
-use arinc429::{Label, decode, encode};
+use arinc429::{Label, decode, encode, to_twos_complement_19};
 
 fn main() {
     // Ground Speed: 250 knots
@@ -32,12 +32,7 @@ fn main() {
 
     // TAT: -50.0 °C
     let tat_celsius = -50.0_f64;
-    let tat_raw_signed = (tat_celsius / 0.25) as i32;
-    let tat_raw = if tat_raw_signed < 0 {
-        ((tat_raw_signed + 0x80000) as u32) & 0x7FFFF
-    } else {
-        tat_raw_signed as u32
-    };
+    let tat_raw = to_twos_complement_19((tat_celsius / 0.25) as i32).unwrap();
     let label_tat = Label::from_octal_str("211").unwrap();
     let encoded_tat = encode(label_tat.raw(), 0, tat_raw, 3).unwrap();
     let decoded_tat = decode(encoded_tat).unwrap();
@@ -0,0 +1,170 @@
+//! Generic BCD digit extraction driven by a per-label digit layout.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::ArincError;
+
+/// A BCD digit group: `(offset, bits, max_digit)` within the 19-bit data field.
+///
+/// `offset` is the bit position of the group's LSB (0 = ARINC bit 11), `bits` its width,
+/// and `max_digit` the largest value the digit may legally take.
+pub type BcdField = (u8, u8, u8);
+
+/// Digit layout of BCD Date (label 260): day, month and year tens/units.
+pub(crate) const DATE_LAYOUT: [BcdField; 6] = [
+    (17, 2, 3), // day tens
+    (13, 4, 9), // day units
+    (12, 1, 1), // month tens
+    (8, 4, 9),  // month units
+    (4, 4, 9),  // year tens
+    (0, 4, 9),  // year units
+];
+
+/// Digit layout of BCD UTC Time (label 150): hour, minute and second tens/units.
+pub(crate) const TIME_LAYOUT: [BcdField; 6] = [
+    (18, 2, 2), // hour tens
+    (14, 4, 9), // hour units
+    (11, 3, 5), // minute tens
+    (7, 4, 9),  // minute units
+    (4, 3, 5),  // second tens
+    (0, 4, 9),  // second units
+];
+
+/// Extract BCD digits from a data field according to `fields`.
+///
+/// Digits are returned in the order of `fields`. Returns `None` if any digit exceeds its
+/// group's `max_digit`.
+///
+/// ```rust
+/// use arinc429::decode_bcd;
+///
+/// // Two digits: tens in bits 4-7, units in bits 0-3
+/// assert_eq!(decode_bcd(0x42, &[(4, 4, 9), (0, 4, 9)]), Some(vec![4, 2]));
+/// assert_eq!(decode_bcd(0x4A, &[(4, 4, 9), (0, 4, 9)]), None);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_bcd(data: u32, fields: &[BcdField]) -> Option<Vec<u8>> {
+    fields
+        .iter()
+        .map(|&(offset, bits, max_digit)| {
+            let digit = ((data >> offset) & crate::field_mask(bits)) as u8;
+            (digit <= max_digit).then_some(digit)
+        })
+        .collect()
+}
+
+/// Fixed-size counterpart of [`decode_bcd`] used by the allocation-free decoders.
+pub(crate) fn digits<const N: usize>(data: u32, fields: &[BcdField; N]) -> Option<[u8; N]> {
+    let mut out = [0u8; N];
+    for (slot, &(offset, bits, max_digit)) in out.iter_mut().zip(fields) {
+        let digit = ((data >> offset) & crate::field_mask(bits)) as u8;
+        if digit > max_digit {
+            return None;
+        }
+        *slot = digit;
+    }
+    Some(out)
+}
+
+/// Pack BCD digits into a data field according to `fields` (the inverse of [`decode_bcd`]).
+fn encode_digits(digits: &[u8], fields: &[BcdField]) -> Result<u32, ArincError> {
+    let mut data = 0u32;
+    for (&digit, &(offset, _, max_digit)) in digits.iter().zip(fields) {
+        if digit > max_digit {
+            return Err(ArincError::InvalidBcd);
+        }
+        data |= u32::from(digit) << offset;
+    }
+
+    if data > 0x7FFFF {
+        return Err(ArincError::InvalidBcd);
+    }
+    Ok(data)
+}
+
+/// Encode a UTC time as the 19-bit BCD data field of label 150, for use with [`encode`].
+///
+/// Returns [`ArincError::InvalidBcd`] if a component is out of range. Only one hour-tens bit
+/// fits in the 19-bit field, so hours 20–23 are also rejected.
+///
+/// [`encode`]: crate::encode
+pub fn encode_bcd_time(hour: u8, minute: u8, second: u8) -> Result<u32, ArincError> {
+    if hour > 23 || minute > 59 || second > 59 {
+        return Err(ArincError::InvalidBcd);
+    }
+
+    encode_digits(
+        &[
+            hour / 10,
+            hour % 10,
+            minute / 10,
+            minute % 10,
+            second / 10,
+            second % 10,
+        ],
+        &TIME_LAYOUT,
+    )
+}
+
+/// Encode a date as the 19-bit BCD data field of label 260, for use with [`encode`].
+///
+/// `year` is the two-digit year (0–99). Returns [`ArincError::InvalidBcd`] if a component
+/// is out of range.
+///
+/// [`encode`]: crate::encode
+pub fn encode_bcd_date(day: u8, month: u8, year: u8) -> Result<u32, ArincError> {
+    if !(1..=31).contains(&day) || !(1..=12).contains(&month) || year > 99 {
+        return Err(ArincError::InvalidBcd);
+    }
+
+    encode_digits(
+        &[
+            day / 10,
+            day % 10,
+            month / 10,
+            month % 10,
+            year / 10,
+            year % 10,
+        ],
+        &DATE_LAYOUT,
+    )
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::{decode, encode};
+
+    #[test]
+    fn test_encode_bcd_time() {
+        let word = encode(104, 0, encode_bcd_time(12, 34, 56).unwrap(), 3).unwrap();
+        assert_eq!(
+            decode(word).unwrap().to_bcd_time(),
+            Some("12:34:56".to_string())
+        );
+
+        assert_eq!(encode_bcd_time(24, 0, 0), Err(ArincError::InvalidBcd));
+        assert_eq!(encode_bcd_time(12, 60, 0), Err(ArincError::InvalidBcd));
+        assert_eq!(encode_bcd_time(21, 0, 0), Err(ArincError::InvalidBcd));
+    }
+
+    #[test]
+    fn test_encode_bcd_date() {
+        let word = encode(176, 0, encode_bcd_date(6, 1, 26).unwrap(), 3).unwrap();
+        assert_eq!(
+            decode(word).unwrap().to_bcd_date(),
+            Some("06-01-26".to_string())
+        );
+
+        let word = encode(176, 0, encode_bcd_date(31, 12, 99).unwrap(), 3).unwrap();
+        assert_eq!(
+            decode(word).unwrap().to_bcd_date(),
+            Some("31-12-99".to_string())
+        );
+
+        assert_eq!(encode_bcd_date(0, 1, 26), Err(ArincError::InvalidBcd));
+        assert_eq!(encode_bcd_date(1, 13, 26), Err(ArincError::InvalidBcd));
+        assert_eq!(encode_bcd_date(1, 1, 100), Err(ArincError::InvalidBcd));
+    }
+}
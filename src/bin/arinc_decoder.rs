@@ -0,0 +1,77 @@
+// src/bin/arinc_decoder.rs
+//
+// Reads hex words from stdin, either as a JSON array of strings or one per line,
+// and prints one JSON object per word.
+
+use std::io::{self, Read};
+use serde::Serialize;
+use arinc429::{decode_hex, ArincWord, Label};
+
+#[derive(Serialize, Default)]
+struct Output {
+    word: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sdi: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ssm: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    units: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bcd: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+fn parse_words(input: &str) -> io::Result<Vec<String>> {
+    if input.trim_start().starts_with('[') {
+        serde_json::from_str(input).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    } else {
+        Ok(input
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect())
+    }
+}
+
+fn describe(decoded: &ArincWord, out: &mut Output) {
+    out.label = Some(decoded.label.octal());
+    out.name = Some(decoded.label.name());
+    out.sdi = Some(decoded.sdi);
+    out.ssm = Some(decoded.ssm.name());
+    match decoded.label {
+        Label::Date => out.bcd = decoded.to_bcd_date(),
+        Label::UtcTime => out.bcd = decoded.to_bcd_time(),
+        _ => {
+            out.value = decoded.to_physical();
+            if out.value.is_some() {
+                out.units = Some(decoded.label.units());
+            }
+        }
+    }
+}
+
+fn main() -> io::Result<()> {
+    let mut input_str = String::new();
+    io::stdin().read_to_string(&mut input_str)?;
+
+    for text in parse_words(&input_str)? {
+        let mut out = Output { word: text.clone(), ..Output::default() };
+
+        match decode_hex(&text) {
+            Ok(decoded) => describe(&decoded, &mut out),
+            Err(e) => out.error = Some(e.to_string()),
+        }
+
+        println!("{}", serde_json::to_string(&out).unwrap());
+    }
+
+    Ok(())
+}
@@ -1,9 +1,9 @@
 // src/bin/arinc_encoder.rs
 
 use std::collections::HashMap;
-use std::io::{self, Read};
+use std::io::{self, Read, Write};
 use serde::{Deserialize, Serialize};
-use arinc429::{encode, Label};
+use arinc429::{encode_batch, Label, Ssm};
 
 #[derive(Deserialize)]
 struct Input {
@@ -17,36 +17,90 @@ struct Output {
     words: HashMap<String, String>,
 }
 
+/// One encoded word, printed per line in `--jsonl` mode.
+#[derive(Serialize)]
+struct Line<'a> {
+    name: &'a str,
+    word: String,
+}
+
+/// Label for each supported input field name.
+fn label_for(name: &str) -> Option<Label> {
+    match name {
+        "GroundSpeed" => Some(Label::GroundSpeed),
+        "PressureAltitude" | "BaroCorrectedAlt" => Some(Label::PressureAltitude),
+        "Mach" => Some(Label::Mach),
+        "TrueAirspeed" => Some(Label::TrueAirspeed),
+        "Tat" => Some(Label::Tat),
+        "RollAngle" => Some(Label::RollAngle),
+        _ => None,
+    }
+}
+
+/// Parse `name,value` rows, skipping blank lines and an optional `name,value` header.
+/// Malformed rows are reported on stderr and skipped.
+fn parse_csv(input: &str) -> Vec<(String, i64)> {
+    let mut rows = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || (index == 0 && line.eq_ignore_ascii_case("name,value")) {
+            continue;
+        }
+        let parsed = line
+            .split_once(',')
+            .and_then(|(name, value)| Some((name.trim(), value.trim().parse::<i64>().ok()?)));
+        match parsed {
+            Some((name, value)) if !name.is_empty() => rows.push((name.to_string(), value)),
+            _ => eprintln!("Skipping malformed CSV row {}: {}", index + 1, line),
+        }
+    }
+    rows
+}
+
 fn main() -> io::Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    // --jsonl streams one object per word instead of a single object with all of them.
+    let jsonl = args.iter().any(|arg| arg == "--jsonl");
+    // --csv reads `name,value` rows instead of a JSON object.
+    let csv = args.iter().any(|arg| arg == "--csv");
+
     let mut input_str = String::new();
     io::stdin().read_to_string(&mut input_str)?;
 
-    let input: Input = serde_json::from_str(&input_str).map_err(|e| {
-        io::Error::new(io::ErrorKind::InvalidData, e)
-    })?;
+    let entries = if csv {
+        parse_csv(&input_str)
+    } else {
+        let input: Input = serde_json::from_str(&input_str).map_err(|e| {
+            io::Error::new(io::ErrorKind::InvalidData, e)
+        })?;
+        input.labels.into_iter().collect()
+    };
 
-    let mut words = HashMap::new();
-
-    for (name, raw_signed) in input.labels {
-        let (label_u8, raw_u32) = match name.as_str() {
-            "GroundSpeed" => (Label::GroundSpeed.raw(), raw_signed as u32),
-            "PressureAltitude" | "BaroCorrectedAlt" => (Label::PressureAltitude.raw(), raw_signed as u32), // positive for now
-            "Mach" => (Label::Mach.raw(), raw_signed as u32),
-            "TrueAirspeed" => (Label::TrueAirspeed.raw(), raw_signed as u32),
-            "Tat" | "RollAngle" => {
-                let signed = raw_signed as i32;
-                let u32_val = if signed < 0 {
-                    ((signed as i64 + 0x80000) as u32) & 0x7FFFF
-                } else {
-                    signed as u32
-                };
-                let label = if name == "Tat" { Label::Tat.raw() } else { Label::RollAngle.raw() };
-                (label, u32_val)
-            }
-            _ => continue,
+    // Inputs are raw data-field counts; scale them so encode_batch can range-check them.
+    let mut names = Vec::new();
+    let mut params = Vec::new();
+    for (name, raw) in entries {
+        let Some(label) = label_for(&name) else {
+            continue;
         };
+        let Some(resolution) = label.resolution() else {
+            continue;
+        };
+        names.push(name);
+        params.push((label, raw as f64 * resolution));
+    }
 
-        match encode(label_u8, 0, raw_u32, 3) {  // SDI=0, SSM=3 Normal
+    let results = encode_batch(&params, 0, Ssm::NormalOperation);  // SDI=0, Normal
+    let mut words = HashMap::new();
+    let mut stdout = io::stdout().lock();
+    for (name, (_, result)) in names.into_iter().zip(results) {
+        match result {
+            Ok(word) if jsonl => {
+                let line = Line { name: &name, word: format!("{:08X}", word) };
+                serde_json::to_writer(&mut stdout, &line)?;
+                writeln!(stdout)?;
+                stdout.flush()?;
+            }
             Ok(word) => {
                 words.insert(name, format!("{:08X}", word));
             }
@@ -56,8 +110,10 @@ fn main() -> io::Result<()> {
         }
     }
 
-    let output = Output { words };
-    println!("{}", serde_json::to_string(&output).unwrap());
+    if !jsonl {
+        let output = Output { words };
+        writeln!(stdout, "{}", serde_json::to_string(&output).unwrap())?;
+    }
 
     Ok(())
 }
@@ -0,0 +1,173 @@
+//! CRC-16 integrity check for blocks of ARINC 429 words.
+//!
+//! Uses the CRC-16/CCITT-FALSE parameters (polynomial `0x1021`, initial value `0xFFFF`,
+//! no reflection, no final XOR). Only the 19-bit data field (ARINC bits 11–29) of each
+//! word feeds the CRC, most significant bit first; label, SDI, SSM and parity are excluded
+//! so the checksum covers the payload independently of how it was addressed.
+//!
+//! For single words that need integrity beyond the word parity, a 4-bit CRC can instead
+//! be embedded in the data field; see [`encode_with_field_checksum`].
+
+use crate::{encode, get_field, ArincError};
+
+const POLY: u16 = 0x1021;
+const INIT: u16 = 0xFFFF;
+
+/// Width of the value carried alongside a field checksum (ARINC bits 11–25).
+pub const FIELD_VALUE_BITS: u32 = 15;
+
+/// CRC-4/ITU (polynomial `x^4 + x + 1`, initial value 0) over the low 15 bits of
+/// `value`, most significant bit first.
+fn crc4(value: u32) -> u32 {
+    let mut crc = 0u32;
+    for i in (0..FIELD_VALUE_BITS).rev() {
+        let bit = (value >> i) & 1;
+        let top = crc >> 3;
+        crc = (crc << 1) & 0xF;
+        if top ^ bit == 1 {
+            crc ^= 0x3;
+        }
+    }
+    crc
+}
+
+/// Shift the low `bits` bits of `value` into `crc`, most significant bit first.
+fn update(mut crc: u16, value: u32, bits: u32) -> u16 {
+    for i in (0..bits).rev() {
+        let bit = ((value >> i) & 1) as u16;
+        let top = crc >> 15;
+        crc <<= 1;
+        if top ^ bit == 1 {
+            crc ^= POLY;
+        }
+    }
+    crc
+}
+
+/// Compute the CRC-16 over the data fields of `words`.
+pub fn arinc_crc16(words: &[u32]) -> u16 {
+    words
+        .iter()
+        .fold(INIT, |crc, &word| update(crc, (word >> 10) & 0x7FFFF, 19))
+}
+
+/// Check that the data fields of `words` match an expected CRC-16.
+pub fn verify_crc(words: &[u32], expected: u16) -> bool {
+    arinc_crc16(words) == expected
+}
+
+/// Encode a word whose data field carries a 15-bit value protected by its own CRC-4.
+///
+/// Data field layout:
+///
+/// | ARINC bits | Contents |
+/// |---|---|
+/// | 11–25 | `value_bits` |
+/// | 26–29 | CRC-4/ITU of `value_bits`, most significant bit first |
+///
+/// The CRC catches any error burst of up to 4 bits within the field, including double-bit
+/// errors that the word parity misses. It covers the value only, so the word can be
+/// readdressed (label, SDI) without recomputing it.
+///
+/// ```rust
+/// use arinc429::{encode_with_field_checksum, verify_field_checksum};
+///
+/// let word = encode_with_field_checksum(0o107, 0, 0x1234, 3).unwrap();
+/// assert_eq!(verify_field_checksum(word), Some(0x1234));
+/// assert_eq!(verify_field_checksum(word ^ (3 << 12)), None);
+/// ```
+///
+/// # Errors
+/// [`ArincError::DataOverflow`] if `value_bits` does not fit in 15 bits, otherwise as
+/// for [`encode`].
+pub fn encode_with_field_checksum(
+    label: u8,
+    sdi: u8,
+    value_bits: u32,
+    ssm: u8,
+) -> Result<u32, ArincError> {
+    if value_bits >> FIELD_VALUE_BITS != 0 {
+        return Err(ArincError::DataOverflow(value_bits));
+    }
+    encode(
+        label,
+        sdi,
+        value_bits | crc4(value_bits) << FIELD_VALUE_BITS,
+        ssm,
+    )
+}
+
+/// The value from a word built by [`encode_with_field_checksum`], or `None` if the
+/// embedded CRC does not match.
+///
+/// Only the field checksum is checked; use [`decode`](crate::decode) for word parity.
+pub fn verify_field_checksum(word: u32) -> Option<u32> {
+    let value = get_field(word, 25, 11);
+    (get_field(word, 29, 26) == crc4(value)).then_some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ccitt_check_value() {
+        // Standard CRC-16/CCITT-FALSE check value for "123456789"
+        let crc = b"123456789"
+            .iter()
+            .fold(INIT, |crc, &byte| update(crc, u32::from(byte), 8));
+        assert_eq!(crc, 0x29B1);
+    }
+
+    #[test]
+    fn test_word_vector() {
+        // Ground speed 2000, pressure altitude 25000, roll 4500 (all SDI 0, SSM 3)
+        let words = [0xE01F4050, 0x6186A0C1, 0x6046502B];
+        assert_eq!(arinc_crc16(&words), 0xE547);
+        assert!(verify_crc(&words, 0xE547));
+
+        // Label/SDI/SSM/parity bits are not covered
+        let readdressed = [0xE01F4050 ^ 0x300, 0x6186A0C1, 0x6046502B];
+        assert!(verify_crc(&readdressed, 0xE547));
+
+        let corrupted = [0xE01F4050 ^ (1 << 10), 0x6186A0C1, 0x6046502B];
+        assert!(!verify_crc(&corrupted, 0xE547));
+        assert_eq!(arinc_crc16(&[]), INIT);
+    }
+
+    #[test]
+    fn test_crc4_matches_polynomial_division() {
+        // The CRC is the remainder of value·x^4 divided by x^4 + x + 1 (0b10011).
+        for value in [1, 2, 0x4000, 0x1234, 0x7FFF] {
+            let mut remainder = value << 4;
+            for bit in (4..19).rev() {
+                if remainder >> bit & 1 == 1 {
+                    remainder ^= 0b10011 << (bit - 4);
+                }
+            }
+            assert_eq!(crc4(value), remainder);
+        }
+        assert_eq!((crc4(0), crc4(1)), (0, 0b0011));
+    }
+
+    #[test]
+    fn test_field_checksum_roundtrip() {
+        for value in [0, 1, 0x2AAA, 0x5555, 0x7FFF] {
+            let word = encode_with_field_checksum(0o107, 2, value, 3).unwrap();
+            assert!(crate::decode(word).is_ok());
+            assert_eq!(verify_field_checksum(word), Some(value));
+
+            // Every single- and adjacent double-bit error in the data field is caught.
+            for bit in 10..29 {
+                assert_eq!(verify_field_checksum(word ^ (1 << bit)), None);
+                assert_eq!(verify_field_checksum(word ^ (3 << bit)), None);
+            }
+            // Readdressing does not disturb the checksum.
+            assert_eq!(verify_field_checksum(word ^ 0x3FF), Some(value));
+        }
+        assert_eq!(
+            encode_with_field_checksum(0o107, 0, 0x8000, 3),
+            Err(ArincError::DataOverflow(0x8000))
+        );
+    }
+}
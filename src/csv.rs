@@ -0,0 +1,100 @@
+//! CSV export of decoded words for spreadsheet analysis.
+
+use std::io::{self, Write};
+
+use crate::{ArincWord, Label};
+
+const HEADER: &str = "octal_label,name,sdi,ssm,raw_data,physical,units";
+
+/// Write `words` as CSV to `out`, one row per word after a header row.
+///
+/// Columns are `octal_label,name,sdi,ssm,raw_data,physical,units`. `physical` holds the
+/// scaled value for BNR labels, the formatted date or time for BCD labels, and is empty
+/// when the word cannot be interpreted (unknown label, non-normal SSM, invalid BCD).
+///
+/// ```rust
+/// use arinc429::{decode, encode, write_csv};
+///
+/// let word = decode(encode(0o012, 0, 2000, 3).unwrap()).unwrap();
+/// let mut out = Vec::new();
+/// write_csv([word].into_iter(), &mut out).unwrap();
+/// assert_eq!(
+///     String::from_utf8(out).unwrap().lines().nth(1),
+///     Some("012,Ground Speed,0,Normal Operation,2000,250,knots")
+/// );
+/// ```
+pub fn write_csv<W: Write>(words: impl Iterator<Item = ArincWord>, mut out: W) -> io::Result<()> {
+    writeln!(out, "{}", HEADER)?;
+    for word in words {
+        let physical = match word.label {
+            Label::Date => word.to_bcd_date(),
+            Label::UtcTime => word.to_bcd_time(),
+            _ => word.to_physical().map(|value| value.to_string()),
+        }
+        .unwrap_or_default();
+
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{}",
+            word.label.octal(),
+            escape(word.label.name()),
+            word.sdi,
+            escape(word.ssm.name()),
+            word.data,
+            physical,
+            escape(word.label.units()),
+        )?;
+    }
+    out.flush()
+}
+
+/// Quote a field if it contains a delimiter, quote or line break (RFC 4180).
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, encode, encode_bcd_time};
+
+    fn rows(words: &[u32]) -> Vec<String> {
+        let mut out = Vec::new();
+        write_csv(words.iter().map(|&w| decode(w).unwrap()), &mut out).unwrap();
+        String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn test_header_only_for_empty_stream() {
+        assert_eq!(rows(&[]), vec![HEADER.to_string()]);
+    }
+
+    #[test]
+    fn test_bnr_bcd_and_unknown_rows() {
+        let rows = rows(&[
+            encode(0o324, 1, 0x7FFFF, 3).unwrap(),
+            encode(0o150, 0, encode_bcd_time(12, 34, 56).unwrap(), 3).unwrap(),
+            encode(0o377, 0, 42, 3).unwrap(),
+        ]);
+        assert_eq!(rows[1], "324,Roll Angle,1,Normal Operation,524287,-0.01,°");
+        assert!(rows[2].starts_with("150,UTC Time,0,Normal Operation,"));
+        assert!(rows[2].contains(",12:34:56,"));
+        assert!(rows[3].starts_with("377,"));
+        assert!(rows[3].contains(",42,,"));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("Ground Speed"), "Ground Speed");
+        assert_eq!(escape("a,b"), "\"a,b\"");
+        assert_eq!(escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
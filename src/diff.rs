@@ -0,0 +1,166 @@
+//! Field-by-field comparison of two raw words, for readable regression test failures.
+
+use core::fmt;
+
+use crate::{decode_unchecked, Label, Ssm};
+
+/// Fields that differ between two words, as `(old, new)` pairs. See [`diff`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WordDiff {
+    /// Changed label
+    pub label: Option<(Label, Label)>,
+    /// Changed SDI
+    pub sdi: Option<(u8, u8)>,
+    /// Changed SSM
+    pub ssm: Option<(Ssm, Ssm)>,
+    /// Changed raw data field
+    pub data: Option<(u32, u32)>,
+    /// Changed parity validity (`true` = valid odd parity)
+    pub parity: Option<(bool, bool)>,
+    /// `new - old` in physical units, when both words carry the same BNR label and the
+    /// data differs
+    pub physical_delta: Option<f64>,
+    /// Units of `physical_delta`
+    units: &'static str,
+}
+
+impl WordDiff {
+    /// Whether the two words were identical.
+    pub fn is_empty(&self) -> bool {
+        self.label.is_none()
+            && self.sdi.is_none()
+            && self.ssm.is_none()
+            && self.data.is_none()
+            && self.parity.is_none()
+    }
+}
+
+/// Compare two raw words field by field.
+///
+/// Words are unpacked without rejecting bad parity, so a parity change is reported
+/// rather than hiding the other fields. The SSM is ignored when computing
+/// [`WordDiff::physical_delta`].
+///
+/// ```rust
+/// use arinc429::{diff, encode};
+///
+/// let golden = encode(0o012, 0, 2000, 3).unwrap();
+/// let actual = encode(0o012, 0, 1999, 1).unwrap();
+/// assert_eq!(
+///     diff(golden, actual).to_string(),
+///     "SSM Normal Operation→No Computed Data, data 2000→1999 (Δ -0.125 knots)"
+/// );
+/// ```
+pub fn diff(a: u32, b: u32) -> WordDiff {
+    let ((old, old_parity), (new, new_parity)) = (decode_unchecked(a), decode_unchecked(b));
+
+    let physical_delta = if old.label == new.label && old.data != new.data {
+        old.label.spec().and_then(|spec| {
+            let before = spec.value(&old)?;
+            let after = spec.value(&new)?;
+            Some(after - before)
+        })
+    } else {
+        None
+    };
+
+    WordDiff {
+        label: changed(old.label, new.label),
+        sdi: changed(old.sdi, new.sdi),
+        ssm: changed(old.ssm, new.ssm),
+        data: changed(old.data, new.data),
+        parity: changed(old_parity, new_parity),
+        physical_delta,
+        units: new.label.units(),
+    }
+}
+
+fn changed<T: PartialEq>(old: T, new: T) -> Option<(T, T)> {
+    (old != new).then_some((old, new))
+}
+
+impl fmt::Display for WordDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "identical");
+        }
+
+        let mut sep = "";
+        if let Some((old, new)) = self.label {
+            let (mut a, mut b) = ([0u8; 3], [0u8; 3]);
+            write!(f, "label {}→{}", old.octal_to(&mut a), new.octal_to(&mut b))?;
+            sep = ", ";
+        }
+        if let Some((old, new)) = self.sdi {
+            write!(f, "{}SDI {}→{}", sep, old, new)?;
+            sep = ", ";
+        }
+        if let Some((old, new)) = self.ssm {
+            write!(f, "{}SSM {}→{}", sep, old.name(), new.name())?;
+            sep = ", ";
+        }
+        if let Some((old, new)) = self.data {
+            write!(f, "{}data {}→{}", sep, old, new)?;
+            if let Some(delta) = self.physical_delta {
+                write!(f, " (Δ {:?} {})", delta, self.units)?;
+            }
+            sep = ", ";
+        }
+        if let Some((old, new)) = self.parity {
+            let state = |valid| if valid { "valid" } else { "invalid" };
+            write!(f, "{}parity {}→{}", sep, state(old), state(new))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode;
+
+    #[test]
+    fn test_identical_words() {
+        let word = encode(0o203, 1, 1000, 3).unwrap();
+        let d = diff(word, word);
+        assert!(d.is_empty());
+        assert_eq!(d.physical_delta, None);
+        assert_eq!(d.to_string(), "identical");
+    }
+
+    #[test]
+    fn test_signed_physical_delta() {
+        let before = encode(0o324, 0, 100, 3).unwrap();
+        let after = encode(0o324, 0, 0x7FFFF, 3).unwrap(); // -1 count
+        let d = diff(before, after);
+        assert_eq!(d.data, Some((100, 0x7FFFF)));
+        assert!((d.physical_delta.unwrap() + 1.01).abs() < 1e-9);
+        assert_eq!((d.label, d.sdi, d.ssm, d.parity), (None, None, None, None));
+    }
+
+    #[test]
+    fn test_label_sdi_and_parity_changes() {
+        let a = encode(0o012, 0, 2000, 3).unwrap();
+        let b = encode(0o203, 2, 2000, 3).unwrap() ^ (1 << 31);
+        let d = diff(a, b);
+        assert_eq!(d.label, Some((Label::GroundSpeed, Label::PressureAltitude)));
+        assert_eq!(d.sdi, Some((0, 2)));
+        assert_eq!(d.parity, Some((true, false)));
+        assert_eq!(d.physical_delta, None);
+        assert_eq!(
+            d.to_string(),
+            "label 012→203, SDI 0→2, parity valid→invalid"
+        );
+    }
+
+    #[test]
+    fn test_no_delta_for_bcd() {
+        let d = diff(
+            encode(0o260, 0, 1, 3).unwrap(),
+            encode(0o260, 0, 2, 3).unwrap(),
+        );
+        assert_eq!(d.data, Some((1, 2)));
+        assert_eq!(d.physical_delta, None);
+        assert_eq!(d.to_string(), "data 1→2");
+    }
+}
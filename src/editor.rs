@@ -0,0 +1,156 @@
+//! In-place modification of individual fields of a raw word.
+
+use crate::{
+    encode_physical, fix_parity, get_field, set_field, ArincError, Label, Sdi, Ssm, REVERSE,
+};
+
+/// Edits the fields of a raw word without a full decode/encode cycle.
+///
+/// Every setter touches only its own bits and then recomputes odd parity, so bits the
+/// editor has no setter for (and any non-standard content in them) are preserved.
+///
+/// ```rust
+/// use arinc429::{decode, encode, Ssm, WordEditor};
+///
+/// let mut editor = WordEditor::new(encode(0o012, 0, 2000, 3).unwrap());
+/// editor.set_ssm(Ssm::NoComputedData);
+/// editor.set_data(1999).unwrap();
+///
+/// let word = decode(editor.word()).unwrap();
+/// assert_eq!((word.ssm, word.data), (Ssm::NoComputedData, 1999));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WordEditor(u32);
+
+impl WordEditor {
+    /// Start editing `word`. Its parity is not checked and is only fixed by the first
+    /// setter call.
+    pub const fn new(word: u32) -> Self {
+        Self(word)
+    }
+
+    /// The edited word.
+    pub const fn word(&self) -> u32 {
+        self.0
+    }
+
+    /// Replace the label with the raw code `label` (e.g. `0o203`).
+    pub fn set_label(&mut self, label: u8) -> &mut Self {
+        self.update(8, 1, u32::from(REVERSE[usize::from(label)]))
+    }
+
+    /// Replace the SDI (bits 9–10).
+    ///
+    /// # Errors
+    /// [`ArincError::InvalidSdi`] if `sdi` is greater than 3; the word is left unchanged.
+    pub fn set_sdi(&mut self, sdi: u8) -> Result<&mut Self, ArincError> {
+        let sdi = Sdi::from_u8(sdi)?;
+        Ok(self.update(10, 9, u32::from(sdi.as_u8())))
+    }
+
+    /// Replace the SSM (bits 30–31).
+    pub fn set_ssm(&mut self, ssm: Ssm) -> &mut Self {
+        self.update(31, 30, u32::from(ssm.as_u8()))
+    }
+
+    /// Replace the whole 19-bit data field (bits 11–29).
+    ///
+    /// # Errors
+    /// [`ArincError::DataOverflow`] if `data` does not fit in 19 bits.
+    pub fn set_data(&mut self, data: u32) -> Result<&mut Self, ArincError> {
+        if data > 0x7FFFF {
+            return Err(ArincError::DataOverflow(data));
+        }
+        Ok(self.update(29, 11, data))
+    }
+
+    /// Set the label to `label` and the data field to `value` scaled for it.
+    ///
+    /// SDI and SSM are kept.
+    ///
+    /// # Errors
+    /// As for [`encode_physical`]; the word is left unchanged.
+    pub fn set_physical(&mut self, label: Label, value: f64) -> Result<&mut Self, ArincError> {
+        let encoded = encode_physical(label, value, 0, Ssm::NormalOperation)?;
+        self.set_label(label.raw());
+        Ok(self.update(29, 11, get_field(encoded, 29, 11)))
+    }
+
+    fn update(&mut self, arinc_msb: u8, arinc_lsb: u8, value: u32) -> &mut Self {
+        self.0 = fix_parity(set_field(self.0, arinc_msb, arinc_lsb, value));
+        self
+    }
+}
+
+impl From<u32> for WordEditor {
+    fn from(word: u32) -> Self {
+        Self::new(word)
+    }
+}
+
+impl From<WordEditor> for u32 {
+    fn from(editor: WordEditor) -> Self {
+        editor.word()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_parity, decode, encode};
+
+    #[test]
+    fn test_set_sdi_preserves_data() {
+        let original = encode(0o203, 0, 0x5A5A5, 3).unwrap();
+        let mut editor = WordEditor::new(original);
+        for sdi in [3, 1, 2, 0] {
+            editor.set_sdi(sdi).unwrap();
+            let word = editor.word();
+            assert!(check_parity(word));
+            assert_eq!(get_field(word, 29, 11), get_field(original, 29, 11));
+            assert_eq!(get_field(word, 31, 30), get_field(original, 31, 30));
+            assert_eq!(decode(word).unwrap().sdi, sdi);
+        }
+        assert_eq!(editor.word(), original);
+    }
+
+    #[test]
+    fn test_invalid_values_leave_word_unchanged() {
+        let original = encode(0o012, 1, 2000, 3).unwrap();
+        let mut editor = WordEditor::new(original);
+        assert_eq!(editor.set_sdi(4).err(), Some(ArincError::InvalidSdi(4)));
+        assert_eq!(
+            editor.set_data(0x80000).err(),
+            Some(ArincError::DataOverflow(0x80000))
+        );
+        assert!(editor.set_physical(Label::GroundSpeed, -1.0).is_err());
+        assert_eq!(editor.word(), original);
+    }
+
+    #[test]
+    fn test_set_physical_and_chaining() {
+        let mut editor = WordEditor::new(encode(0o012, 2, 0, 1).unwrap());
+        editor
+            .set_physical(Label::RollAngle, -2.0)
+            .unwrap()
+            .set_ssm(Ssm::NormalOperation);
+        let word = decode(editor.word()).unwrap();
+        assert_eq!(word.label, Label::RollAngle);
+        assert_eq!(word.sdi, 2);
+        assert_eq!(word.to_physical(), Some(-2.0));
+        assert_eq!(
+            editor.word(),
+            encode_physical(Label::RollAngle, -2.0, 2, Ssm::NormalOperation).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_set_label() {
+        let mut editor = WordEditor::from(0u32);
+        editor.set_label(0o203);
+        assert_eq!(
+            decode(editor.into()).unwrap().label,
+            Label::PressureAltitude
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! Field-by-field breakdown of a raw word, for debugging hand-built words.
+
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Write;
+
+use crate::{decode_unchecked, get_field};
+
+/// Describe each field of `word` with its ARINC bit range, raw bits and meaning.
+///
+/// Fields are listed from bit 32 down to bit 1. The label line shows the byte as
+/// transmitted and after bit reversal. Parity is reported rather than enforced, and the
+/// data line is scaled whatever the SSM, so a word that fails to decode can still be
+/// inspected.
+///
+/// ```rust
+/// use arinc429::explain;
+///
+/// print!("{}", explain(0xE01F4050));
+/// // word    0xE01F4050
+/// // parity  [32]     1                      odd parity valid
+/// // ssm     [31-30]  11                     Normal Operation
+/// // data    [29-11]  0000000011111010000    2000 = 250.0 knots
+/// // sdi     [10-9]   00                     0
+/// // label   [8-1]    01010000 -> 00001010   012 Ground Speed
+/// ```
+pub fn explain(word: u32) -> String {
+    let (decoded, parity_ok) = decode_unchecked(word);
+    let mut out = format!("word    0x{:08X}\n", word);
+    let mut line = |name: &str, msb: u8, lsb: u8, bits: &str, meaning: &dyn core::fmt::Display| {
+        let range = if msb == lsb {
+            format!("[{}]", msb)
+        } else {
+            format!("[{}-{}]", msb, lsb)
+        };
+        // Writing to a String cannot fail.
+        let _ = writeln!(out, "{:<8}{:<9}{:<23}{}", name, range, bits, meaning);
+    };
+
+    let bin = |msb: u8, lsb: u8| {
+        let width = usize::from(msb - lsb + 1);
+        format!("{:0width$b}", get_field(word, msb, lsb), width = width)
+    };
+
+    let parity = if parity_ok {
+        "odd parity valid"
+    } else {
+        "odd parity INVALID"
+    };
+    line("parity", 32, 32, &bin(32, 32), &parity);
+    line("ssm", 31, 30, &bin(31, 30), &decoded.ssm.name());
+
+    let mut data = format!("{}", decoded.data);
+    if let Some(value) = decoded.to_physical_raw() {
+        let _ = write!(data, " = {:?} {}", value, decoded.label.units());
+    } else if let Some(date) = decoded.to_date() {
+        let _ = write!(data, " = {}", date);
+    } else if let Some(time) = decoded.to_time() {
+        let _ = write!(data, " = {}", time);
+    }
+    if decoded.label.sdi_is_data() {
+        line("data", 29, 9, &bin(29, 9), &data.trim_end());
+    } else {
+        line("data", 29, 11, &bin(29, 11), &data.trim_end());
+        line("sdi", 10, 9, &bin(10, 9), &decoded.sdi);
+    }
+
+    let wire = bin(8, 1);
+    let label = format!("{} -> {:08b}", wire, decoded.label.raw());
+    let mut octal = [0u8; 3];
+    let meaning = format!(
+        "{} {}",
+        decoded.label.octal_to(&mut octal),
+        decoded.label.name()
+    );
+    line("label", 8, 1, &label, &meaning);
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{encode, encode_bcd_time};
+
+    #[test]
+    fn test_ground_speed_snapshot() {
+        assert_eq!(
+            explain(0xE01F4050),
+            "\
+word    0xE01F4050
+parity  [32]     1                      odd parity valid
+ssm     [31-30]  11                     Normal Operation
+data    [29-11]  0000000011111010000    2000 = 250.0 knots
+sdi     [10-9]   00                     0
+label   [8-1]    01010000 -> 00001010   012 Ground Speed
+"
+        );
+    }
+
+    #[test]
+    fn test_bad_parity_and_bcd() {
+        let word = encode(0o150, 1, encode_bcd_time(12, 34, 56).unwrap(), 3).unwrap();
+        let text = explain(word ^ (1 << 31));
+        assert!(text.contains("odd parity INVALID"), "{}", text);
+        assert!(text.contains(" = 12:34:56\n"), "{}", text);
+        assert!(text.contains("sdi     [10-9]   01 "), "{}", text);
+        assert!(text.ends_with("150 UTC Time\n"), "{}", text);
+    }
+
+    #[test]
+    fn test_sdi_bits_shown_as_data() {
+        let text = explain(encode(0o110, 3, 0x10000, 3).unwrap());
+        assert!(
+            text.contains("data    [29-9]   001000000000000000011  262147 = "),
+            "{}",
+            text
+        );
+        assert!(!text.contains("sdi"), "{}", text);
+    }
+}
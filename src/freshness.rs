@@ -0,0 +1,118 @@
+//! Staleness monitoring for received parameters.
+
+use alloc::collections::BTreeMap;
+use core::time::Duration;
+
+use crate::{ArincWord, Label};
+
+/// Tracks when each `(label, SDI)` was last received and flags those that have stopped
+/// updating.
+///
+/// A parameter is stale once more than its timeout has passed since its last word. The
+/// timeout defaults to the maximum of [`Label::transmission_interval`] and can be
+/// overridden per label. Timestamps are any monotonic clock expressed as a [`Duration`],
+/// e.g. time since the receiver started.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use arinc429::{decode, FreshnessTracker, Label};
+///
+/// let mut tracker = FreshnessTracker::new();
+/// tracker.update(&decode(0xE01F4050).unwrap(), Duration::ZERO); // ground speed
+///
+/// // Ground speed must repeat at least every 50 ms.
+/// assert!(!tracker.is_stale(Label::GroundSpeed, 0, Duration::from_millis(50)));
+/// assert!(tracker.is_stale(Label::GroundSpeed, 0, Duration::from_millis(51)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FreshnessTracker {
+    last_seen: BTreeMap<(u8, u8), Duration>,
+    timeouts: BTreeMap<u8, Duration>,
+}
+
+impl FreshnessTracker {
+    /// Create a tracker with no words received.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use `timeout` for `label` instead of its standard maximum interval.
+    pub fn set_timeout(&mut self, label: Label, timeout: Duration) {
+        self.timeouts.insert(label.raw(), timeout);
+    }
+
+    /// Record that `word` was received at `timestamp`.
+    pub fn update(&mut self, word: &ArincWord, timestamp: Duration) {
+        self.last_seen
+            .insert((word.label.raw(), word.sdi), timestamp);
+    }
+
+    /// Timeout applied to `label`: the override, else its standard maximum interval.
+    pub fn timeout(&self, label: Label) -> Option<Duration> {
+        self.timeouts
+            .get(&label.raw())
+            .copied()
+            .or_else(|| label.transmission_interval().map(|(_, max)| max))
+    }
+
+    /// Whether `label` from `sdi` is stale at `now`.
+    ///
+    /// A parameter never received is stale. One with no timeout (an unknown label with no
+    /// override) never goes stale once received.
+    pub fn is_stale(&self, label: Label, sdi: u8, now: Duration) -> bool {
+        let Some(&last) = self.last_seen.get(&(label.raw(), sdi)) else {
+            return true;
+        };
+        match self.timeout(label) {
+            Some(timeout) => now.saturating_sub(last) > timeout,
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, encode};
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    fn word(label: u8, sdi: u8) -> ArincWord {
+        decode(encode(label, sdi, 0, 3).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_goes_stale_after_interval() {
+        let mut tracker = FreshnessTracker::new();
+        assert!(tracker.is_stale(Label::PressureAltitude, 0, ms(0)));
+
+        let (_, max) = Label::PressureAltitude.transmission_interval().unwrap();
+        tracker.update(&word(0o203, 0), ms(1000));
+        assert!(!tracker.is_stale(Label::PressureAltitude, 0, ms(1000) + max));
+        assert!(tracker.is_stale(Label::PressureAltitude, 0, ms(1001) + max));
+
+        // A fresh word resets the clock; other SDIs are tracked separately.
+        tracker.update(&word(0o203, 0), ms(1001) + max);
+        assert!(!tracker.is_stale(Label::PressureAltitude, 0, ms(1001) + max));
+        assert!(tracker.is_stale(Label::PressureAltitude, 1, ms(1001) + max));
+    }
+
+    #[test]
+    fn test_timeout_override() {
+        let mut tracker = FreshnessTracker::new();
+        tracker.set_timeout(Label::GroundSpeed, ms(500));
+        tracker.set_timeout(Label::Unknown(0o100), ms(10));
+        assert_eq!(tracker.timeout(Label::GroundSpeed), Some(ms(500)));
+        tracker.update(&word(0o012, 2), ms(0));
+        tracker.update(&word(0o100, 0), ms(0));
+        assert!(!tracker.is_stale(Label::GroundSpeed, 2, ms(400)));
+        assert!(tracker.is_stale(Label::Unknown(0o100), 0, ms(11)));
+
+        // No standard interval and no override: never stale once seen.
+        tracker.update(&word(0o111, 0), ms(0));
+        assert_eq!(tracker.timeout(Label::Unknown(0o111)), None);
+        assert!(!tracker.is_stale(Label::Unknown(0o111), 0, ms(1_000_000)));
+    }
+}
@@ -0,0 +1,94 @@
+//! Landing gear position from the gear discrete word (label 272).
+//!
+//! Each gear leg reports two lock switches, one ARINC bit each:
+//!
+//! | Bit | Meaning |
+//! |---|---|
+//! | 11 | Nose gear down and locked |
+//! | 12 | Left main gear down and locked |
+//! | 13 | Right main gear down and locked |
+//! | 14 | Nose gear up and locked |
+//! | 15 | Left main gear up and locked |
+//! | 16 | Right main gear up and locked |
+//!
+//! Bits 17–29 are spare.
+
+use crate::{ArincWord, Label};
+
+/// Overall landing gear position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GearState {
+    /// Every leg up and locked
+    Up,
+    /// Every leg down and locked
+    Down,
+    /// Any other combination, e.g. during extension or retraction
+    InTransit,
+}
+
+/// One bit per leg: nose, left main, right main.
+const ALL_LEGS: u32 = 0b111;
+
+impl ArincWord {
+    /// Landing gear position carried by a [`Label::GearPosition`] word.
+    ///
+    /// Returns `None` for any other label, if the SSM is not `00` (valid data under the
+    /// discrete-word convention), or if a leg reports both up and down locked.
+    ///
+    /// ```rust
+    /// use arinc429::{decode, encode, GearState};
+    ///
+    /// let word = decode(encode(0o272, 0, 0b111, 0).unwrap()).unwrap();
+    /// assert_eq!(word.gear_state(), Some(GearState::Down));
+    /// ```
+    pub fn gear_state(&self) -> Option<GearState> {
+        if self.label != Label::GearPosition || self.ssm_raw() != 0 {
+            return None;
+        }
+        let down = self.data & ALL_LEGS;
+        let up = (self.data >> 3) & ALL_LEGS;
+        if down & up != 0 {
+            return None;
+        }
+        Some(match (down, up) {
+            (ALL_LEGS, 0) => GearState::Down,
+            (0, ALL_LEGS) => GearState::Up,
+            _ => GearState::InTransit,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, encode};
+
+    fn gear(data: u32) -> Option<GearState> {
+        decode(encode(0o272, 0, data, 0).unwrap())
+            .unwrap()
+            .gear_state()
+    }
+
+    #[test]
+    fn test_gear_states() {
+        assert_eq!(gear(0b000_111), Some(GearState::Down));
+        assert_eq!(gear(0b111_000), Some(GearState::Up));
+        // Nose locked down, mains unlocked.
+        assert_eq!(gear(0b000_001), Some(GearState::InTransit));
+        assert_eq!(gear(0), Some(GearState::InTransit));
+        // Spare bits are ignored.
+        assert_eq!(gear(0x7FFC0 | 0b111), Some(GearState::Down));
+    }
+
+    #[test]
+    fn test_gear_state_invalid() {
+        // Left main reports both up and down locked.
+        assert_eq!(gear(0b010_010), None);
+        let ncd = decode(encode(0o272, 0, 0b111, 1).unwrap()).unwrap();
+        assert_eq!(ncd.gear_state(), None);
+        let other = decode(encode(0o273, 0, 0b111, 0).unwrap()).unwrap();
+        assert_eq!(other.label, Label::FlapPosition);
+        assert_eq!(other.gear_state(), None);
+    }
+}
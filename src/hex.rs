@@ -0,0 +1,106 @@
+//! Parsing and formatting of words as 8-digit hexadecimal strings (e.g. `"E01F4050"`).
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+use crate::{decode, ArincError, ArincWord};
+
+/// Parse a word written as exactly 8 hex digits, with an optional `0x`/`0X` prefix.
+///
+/// Surrounding whitespace is ignored and digits may be either case.
+///
+/// ```rust
+/// use arinc429::word_from_hex;
+///
+/// assert_eq!(word_from_hex("0xE01F4050"), Ok(0xE01F4050));
+/// assert!(word_from_hex("1F4050").is_err());
+/// ```
+pub fn word_from_hex(s: &str) -> Result<u32, ArincError> {
+    let s = s.trim();
+    let digits = s
+        .strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s);
+    if digits.len() != 8 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(ArincError::InvalidHex);
+    }
+    u32::from_str_radix(digits, 16).map_err(|_| ArincError::InvalidHex)
+}
+
+/// Format a word in canonical form: 8 zero-padded uppercase hex digits, no prefix.
+#[cfg(feature = "alloc")]
+pub fn word_to_hex(word: u32) -> String {
+    word_to_hex_buf(word, &mut [0; 8]).to_string()
+}
+
+/// Write a word in canonical form into `buf` without allocating, for `no_std` logging.
+///
+/// ```rust
+/// use arinc429::word_to_hex_buf;
+///
+/// let mut buf = [0u8; 8];
+/// assert_eq!(word_to_hex_buf(0xE01F4050, &mut buf), "E01F4050");
+/// ```
+pub fn word_to_hex_buf(word: u32, buf: &mut [u8; 8]) -> &str {
+    for (i, digit) in buf.iter_mut().enumerate() {
+        let nibble = (word >> (28 - 4 * i)) & 0xF;
+        *digit = b"0123456789ABCDEF"[nibble as usize];
+    }
+    core::str::from_utf8(buf).expect("hex digits are ASCII")
+}
+
+/// Parse a hex word with [`word_from_hex`] and [`decode`] it.
+pub fn decode_hex(s: &str) -> Result<ArincWord, ArincError> {
+    decode(word_from_hex(s)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Label;
+
+    #[test]
+    fn test_word_from_hex() {
+        assert_eq!(word_from_hex("E01F4050"), Ok(0xE01F4050));
+        assert_eq!(word_from_hex("e01f4050"), Ok(0xE01F4050));
+        assert_eq!(word_from_hex(" 0X00000000\n"), Ok(0));
+        for bad in [
+            "",
+            "0x",
+            "E01F405",
+            "E01F40500",
+            "+E01F405",
+            "E01F4G50",
+            "0x 1F4050",
+        ] {
+            assert_eq!(word_from_hex(bad), Err(ArincError::InvalidHex), "{:?}", bad);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_word_to_hex_roundtrip() {
+        assert_eq!(word_to_hex(0x50), "00000050");
+        assert_eq!(word_to_hex(0xE01F4050), "E01F4050");
+        assert_eq!(word_from_hex(&word_to_hex(0xDEADBEEF)), Ok(0xDEADBEEF));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_word_to_hex_buf_matches_string() {
+        let mut buf = [0u8; 8];
+        for word in [0, 0x50, 0xE01F4050, 0x0ABCDEF0, u32::MAX] {
+            assert_eq!(word_to_hex_buf(word, &mut buf), word_to_hex(word));
+            assert_eq!(
+                word_to_hex_buf(word, &mut buf),
+                alloc::format!("{:08X}", word)
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_hex() {
+        assert_eq!(decode_hex("E01F4050").unwrap().label, Label::GroundSpeed);
+        assert_eq!(decode_hex("601F4050"), Err(ArincError::ParityMismatch));
+    }
+}
@@ -0,0 +1,278 @@
+//! Checking a bus capture against an interface control document (ICD).
+//!
+//! An [`Icd`] lists the labels a transmitter must send, with the scaling, valid range and
+//! transmit interval of each. [`check_capture`] replays a timestamped capture against it
+//! and collects every deviation in a [`ConformanceReport`].
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use crate::{decode, unpack_as, ArincError, Label, LabelSpec, LabelTable};
+
+/// ICD requirements for one label.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IcdEntry {
+    /// How to scale the data field
+    pub spec: LabelSpec,
+    /// Allowed `(min, max)` gap between consecutive words from the same SDI
+    pub interval: Option<(Duration, Duration)>,
+    /// Allowed `(min, max)` physical value
+    pub range: Option<(f64, f64)>,
+}
+
+/// The set of labels a capture must contain; see [`check_capture`].
+///
+/// ```rust
+/// use std::time::Duration;
+/// use arinc429::{check_capture, encode, Icd, LabelTable};
+///
+/// let table = LabelTable::builtin();
+/// let mut icd = Icd::new();
+/// icd.require(0o012, &table).unwrap().range = Some((0.0, 400.0));
+/// icd.require(0o203, &table).unwrap();
+///
+/// let capture = [
+///     (Duration::ZERO, encode(0o012, 0, 2000, 3).unwrap()),
+///     (Duration::from_millis(40), encode(0o012, 0, 4000, 3).unwrap()), // 500 kt
+/// ];
+/// let report = check_capture(&capture, &icd);
+/// assert_eq!(report.missing, [0o203]);
+/// assert_eq!(report.out_of_range.len(), 1);
+/// assert!(report.rate_violations.is_empty());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Icd {
+    entries: BTreeMap<u8, IcdEntry>,
+}
+
+impl Icd {
+    /// Create an ICD requiring no labels.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require `code`, scaled as defined in `table`.
+    ///
+    /// For a built-in [`Label`] the interval and range default to
+    /// [`Label::transmission_interval`] and [`Label::range`]; otherwise they are unset.
+    /// Adjust them through the returned entry.
+    ///
+    /// # Errors
+    /// [`ArincError::UnsupportedLabel`] if `table` has no definition for `code`.
+    pub fn require(&mut self, code: u8, table: &LabelTable) -> Result<&mut IcdEntry, ArincError> {
+        let label = Label::from_u8(code);
+        let spec = *table.get(code).ok_or(ArincError::UnsupportedLabel(label))?;
+        Ok(self.insert(
+            code,
+            IcdEntry {
+                spec,
+                interval: label.transmission_interval(),
+                range: label.range(),
+            },
+        ))
+    }
+
+    /// Require `code` with explicit requirements, replacing any earlier entry.
+    pub fn insert(&mut self, code: u8, entry: IcdEntry) -> &mut IcdEntry {
+        self.entries.insert(code, entry);
+        self.entries.get_mut(&code).expect("just inserted")
+    }
+
+    /// Requirements for `code`, if it is part of the ICD.
+    pub fn get(&self, code: u8) -> Option<&IcdEntry> {
+        self.entries.get(&code)
+    }
+}
+
+/// A word whose value lies outside its ICD range.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RangeViolation {
+    /// Capture timestamp of the word
+    pub at: Duration,
+    /// Raw label code
+    pub label: u8,
+    /// SDI of the word
+    pub sdi: u8,
+    /// Physical value received
+    pub value: f64,
+}
+
+/// A gap between consecutive words of one label and SDI outside the ICD interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateViolation {
+    /// Capture timestamp of the later word
+    pub at: Duration,
+    /// Raw label code
+    pub label: u8,
+    /// SDI of the words
+    pub sdi: u8,
+    /// Time since the previous word
+    pub gap: Duration,
+}
+
+/// Deviations from an [`Icd`] found by [`check_capture`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConformanceReport {
+    /// Required labels never received with valid parity, in ascending code order
+    pub missing: Vec<u8>,
+    /// Valid-status words with out-of-range values, in capture order
+    pub out_of_range: Vec<RangeViolation>,
+    /// Transmit gaps outside the required interval, in capture order
+    pub rate_violations: Vec<RateViolation>,
+}
+
+impl ConformanceReport {
+    /// Whether the capture met every requirement.
+    pub fn is_conformant(&self) -> bool {
+        self.missing.is_empty() && self.out_of_range.is_empty() && self.rate_violations.is_empty()
+    }
+}
+
+/// Check a capture of `(timestamp, word)` pairs, in time order, against `icd`.
+///
+/// Words failing parity and labels not in the ICD are ignored. Values are only range
+/// checked when the SSM marks them valid. The first and last word of each label are not
+/// compared against the capture's start and end, so a capture should span at least one
+/// maximum interval of every label.
+pub fn check_capture(words: &[(Duration, u32)], icd: &Icd) -> ConformanceReport {
+    let mut report = ConformanceReport::default();
+    let mut last_seen: BTreeMap<(u8, u8), Duration> = BTreeMap::new();
+
+    for &(at, word) in words {
+        let Ok(decoded) = decode(word) else {
+            continue;
+        };
+        let label = decoded.label.raw();
+        let Some(entry) = icd.get(label) else {
+            continue;
+        };
+        let decoded = unpack_as(word, entry.spec.sdi_is_data);
+        let sdi = decoded.sdi;
+
+        if let Some((min, max)) = entry.range {
+            if let Some(value) = entry.spec.physical(&decoded) {
+                if !(min..=max).contains(&value) {
+                    report.out_of_range.push(RangeViolation {
+                        at,
+                        label,
+                        sdi,
+                        value,
+                    });
+                }
+            }
+        }
+
+        if let Some(previous) = last_seen.insert((label, sdi), at) {
+            let gap = at.saturating_sub(previous);
+            if let Some((min, max)) = entry.interval {
+                if gap < min || gap > max {
+                    report.rate_violations.push(RateViolation {
+                        at,
+                        label,
+                        sdi,
+                        gap,
+                    });
+                }
+            }
+        }
+    }
+
+    report.missing = icd
+        .entries
+        .keys()
+        .filter(|&&code| !last_seen.keys().any(|&(label, _)| label == code))
+        .copied()
+        .collect();
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_conformant_capture() {
+        let table = LabelTable::builtin();
+        let mut icd = Icd::new();
+        icd.require(0o012, &table).unwrap();
+        let capture: Vec<_> = (0..10)
+            .map(|i| (ms(i * 40), encode(0o012, 0, 2000, 3).unwrap()))
+            .collect();
+        let report = check_capture(&capture, &icd);
+        assert!(report.is_conformant(), "{:?}", report);
+    }
+
+    #[test]
+    fn test_non_conformant_capture() {
+        let mut table = LabelTable::builtin();
+        table.insert(0o270, LabelSpec::bnr("Fuel Flow", "kg/h", 1.0, false, 16));
+
+        let mut icd = Icd::new();
+        icd.require(0o012, &table).unwrap(); // every 25-50 ms
+        icd.require(0o203, &table).unwrap();
+        let fuel = icd.require(0o270, &table).unwrap();
+        assert_eq!(fuel.interval, None);
+        fuel.interval = Some((ms(100), ms(200)));
+        fuel.range = Some((0.0, 5000.0));
+
+        let capture = [
+            (ms(0), encode(0o012, 0, 2000, 3).unwrap()),
+            (ms(0), encode(0o270, 1, 1200, 3).unwrap()),
+            (ms(30), encode(0o012, 0, 2000, 3).unwrap()),
+            // Fuel flow too early and too high.
+            (ms(50), encode(0o270, 1, 9000, 3).unwrap()),
+            // Ground speed too late.
+            (ms(81), encode(0o012, 0, 2000, 3).unwrap()),
+            // Out of range but NCD, and a parity failure: no value checks.
+            (ms(200), encode(0o270, 1, 9000, 1).unwrap()),
+            (ms(201), encode(0o203, 0, 1000, 3).unwrap() ^ (1 << 31)),
+            // Not in the ICD.
+            (ms(202), encode(0o100, 0, 0, 3).unwrap()),
+        ];
+        let report = check_capture(&capture, &icd);
+
+        assert!(!report.is_conformant());
+        assert_eq!(report.missing, [0o203]);
+        assert_eq!(
+            report.out_of_range,
+            [RangeViolation {
+                at: ms(50),
+                label: 0o270,
+                sdi: 1,
+                value: 9000.0
+            }]
+        );
+        assert_eq!(
+            report.rate_violations,
+            [
+                RateViolation {
+                    at: ms(50),
+                    label: 0o270,
+                    sdi: 1,
+                    gap: ms(50)
+                },
+                RateViolation {
+                    at: ms(81),
+                    label: 0o012,
+                    sdi: 0,
+                    gap: ms(51)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_require_unknown_code() {
+        let mut icd = Icd::new();
+        assert_eq!(
+            icd.require(0o270, &LabelTable::builtin()).err(),
+            Some(ArincError::UnsupportedLabel(Label::Unknown(0o270)))
+        );
+    }
+}
@@ -0,0 +1,74 @@
+//! Deterministic bit errors for exercising receivers against bus noise.
+//!
+//! Odd parity catches any odd number of flipped bits, so a single-bit error anywhere in
+//! the word makes [`decode`](crate::decode) fail. Bursts of even length go undetected by
+//! parity alone.
+
+use crate::arinc_span;
+
+/// Flip ARINC bit `bit` (1-based, bit 1 = LSB, bit 32 = parity) of a raw word.
+///
+/// ```rust
+/// use arinc429::{check_parity, encode, inject_error};
+///
+/// let word = encode(0o203, 0, 1000, 3).unwrap();
+/// assert!(!check_parity(inject_error(word, 15)));
+/// ```
+///
+/// # Panics
+/// If `bit` is not in `1..=32`.
+pub fn inject_error(word: u32, bit: u8) -> u32 {
+    inject_burst(word, bit, 1)
+}
+
+/// Flip `len` consecutive ARINC bits of a raw word, starting at `start_bit` and moving
+/// towards the MSB. A `len` of zero leaves the word unchanged.
+///
+/// # Panics
+/// If the burst does not lie within bits `1..=32`.
+pub fn inject_burst(word: u32, start_bit: u8, len: u8) -> u32 {
+    if len == 0 {
+        return word;
+    }
+    let (shift, mask) = arinc_span(start_bit.saturating_add(len - 1), start_bit);
+    word ^ (mask << shift)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_parity, decode, encode, ArincError};
+
+    fn word() -> u32 {
+        encode(0o203, 1, 0x1234, 3).unwrap()
+    }
+
+    #[test]
+    fn test_single_bit_errors_break_parity() {
+        // Parity bit, label bit, data bit.
+        for bit in [32, 3, 20] {
+            let corrupted = inject_error(word(), bit);
+            assert_eq!(corrupted ^ word(), 1 << (bit - 1));
+            assert!(!check_parity(corrupted), "bit {}", bit);
+            assert_eq!(decode(corrupted), Err(ArincError::ParityMismatch));
+        }
+        assert_eq!(inject_error(inject_error(word(), 20), 20), word());
+    }
+
+    #[test]
+    fn test_burst_parity() {
+        assert_eq!(inject_burst(word(), 11, 0), word());
+        assert_eq!(inject_burst(word(), 11, 4) ^ word(), 0xF << 10);
+        assert!(!check_parity(inject_burst(word(), 11, 3)));
+        // An even number of flips slips past parity.
+        assert!(check_parity(inject_burst(word(), 11, 2)));
+        assert_eq!(inject_burst(word(), 1, 32), !word());
+        assert!(check_parity(!word()));
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the word")]
+    fn test_burst_past_parity_bit() {
+        inject_burst(word(), 31, 3);
+    }
+}
@@ -0,0 +1,87 @@
+//! ISO Alphabet No. 5 (7-bit ASCII) character data carried in the data field.
+//!
+//! Each character word packs two 7-bit characters LSB-first: ARINC bits 11–17 hold the
+//! first character and bits 18–24 the second. Bits 25–29 are unused. NUL (0x00) is padding.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{ArincWord, Label};
+
+/// ISO-5 record separator, which terminates a character record.
+pub const RECORD_SEPARATOR: char = '\u{1E}';
+
+impl ArincWord {
+    /// Extract the ISO-5 characters packed into the data field.
+    ///
+    /// NUL padding characters are dropped. Character data is carried on
+    /// equipment-specific labels, so this returns `None` for every named [`Label`],
+    /// including discrete ones such as equipment identification, and only decodes
+    /// [`Label::Unknown`] codes.
+    pub fn to_iso5_chars(&self) -> Option<Vec<char>> {
+        if !matches!(self.label, Label::Unknown(_)) {
+            return None;
+        }
+
+        Some(
+            [self.data & 0x7F, (self.data >> 7) & 0x7F]
+                .into_iter()
+                .filter(|&c| c != 0)
+                .map(|c| char::from(c as u8))
+                .collect(),
+        )
+    }
+}
+
+/// Assemble a sequence of character words into a string.
+///
+/// Characters are appended in order until a [`RECORD_SEPARATOR`] is reached (it is not
+/// included). Words that are not character words are skipped.
+pub fn iso5_string<'a>(words: impl IntoIterator<Item = &'a ArincWord>) -> String {
+    words
+        .into_iter()
+        .filter_map(ArincWord::to_iso5_chars)
+        .flatten()
+        .take_while(|&c| c != RECORD_SEPARATOR)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, encode};
+
+    fn char_word(first: u8, second: u8) -> ArincWord {
+        let data = u32::from(first) | (u32::from(second) << 7);
+        decode(encode(0o357, 0, data, 3).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_to_iso5_chars() {
+        assert_eq!(char_word(b'O', b'K').to_iso5_chars(), Some(vec!['O', 'K']));
+        assert_eq!(char_word(b'A', 0).to_iso5_chars(), Some(vec!['A']));
+        assert_eq!(decode(0xE01F4050).unwrap().to_iso5_chars(), None);
+        // Equipment ID 006 on discrete label 377 is not a character word.
+        let equipment_id = decode(encode(0o377, 0, 0x006, 0).unwrap()).unwrap();
+        assert_eq!(equipment_id.to_iso5_chars(), None);
+        // Nor are gear and flap discretes.
+        let gear_down = decode(encode(0o272, 0, 0b111, 0).unwrap()).unwrap();
+        assert_eq!(gear_down.to_iso5_chars(), None);
+        let flaps = decode(encode(0o273, 0, 0b100, 0).unwrap()).unwrap();
+        assert_eq!(flaps.to_iso5_chars(), None);
+    }
+
+    #[test]
+    fn test_iso5_string_stops_at_record_separator() {
+        let words = [
+            char_word(b'B', b'I'),
+            // Discrete gear word interleaved in the stream.
+            decode(encode(0o272, 0, 0b111, 0).unwrap()).unwrap(),
+            char_word(b'T', b'E'),
+            char_word(b' ', b'O'),
+            char_word(b'K', 0x1E),
+            char_word(b'X', b'X'),
+        ];
+        assert_eq!(iso5_string(&words), "BITE OK");
+    }
+}
@@ -12,8 +12,13 @@
 //! - Common flight parameters (ground speed, altitude, Mach, TAT, roll angle, etc.)
 //!
 //! ## Features
-//! - Pure Rust, no_std compatible (with minor changes)
-//! - Comprehensive error handling via [`thiserror`]
+//! - Pure Rust, `no_std` compatible: disable default features for a core-only build
+//! - Cargo features: `std` (default; streaming [`WordReader`]), `alloc` (`String`/`Vec`
+//!   returning APIs and [`LabelTable`]), `serde`, `chrono`, `cli` (the bundled binaries),
+//!   `heapless` (fixed-capacity batch decoding), `test-util`
+//! - A single [`ArincError`] type, deriving `thiserror::Error` by default; the
+//!   `minimal-error` feature (or dropping the `thiserror` feature) swaps in a hand-written
+//!   `Display` with the same messages
 //! - Well-tested with unit tests and cross-validation
 //! - Ready for integration with flight simulators (JSBSim, FlightGear) or real hardware
 //!
@@ -32,37 +37,232 @@
 //! assert_eq!(decoded.to_physical(), Some(250.0));
 //! ```
 
-use thiserror::Error;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::RangeInclusive;
+use core::time::Duration;
+
+mod bcd;
+mod crc;
+#[cfg(feature = "std")]
+mod csv;
+mod diff;
+mod editor;
+#[cfg(feature = "alloc")]
+mod explain;
+#[cfg(feature = "alloc")]
+mod freshness;
+mod gear;
+mod hex;
+#[cfg(feature = "alloc")]
+mod icd;
+mod inject;
+#[cfg(feature = "alloc")]
+mod iso5;
+mod param;
+#[cfg(feature = "alloc")]
+mod pattern;
+mod position;
+#[cfg(feature = "alloc")]
+mod rate;
+#[cfg(feature = "std")]
+mod reader;
+#[cfg(feature = "alloc")]
+pub mod sim;
+mod stats;
+mod table;
+#[cfg(feature = "test-util")]
+mod test_util;
+mod timing;
+mod units;
+#[cfg(feature = "alloc")]
+mod validate;
+mod view;
+#[cfg(feature = "alloc")]
+pub mod williamsburg;
+
+#[cfg(feature = "alloc")]
+pub use bcd::decode_bcd;
+pub use bcd::{encode_bcd_date, encode_bcd_time, BcdField};
+pub use crc::{
+    arinc_crc16, encode_with_field_checksum, verify_crc, verify_field_checksum, FIELD_VALUE_BITS,
+};
+#[cfg(feature = "std")]
+pub use csv::write_csv;
+pub use diff::{diff, WordDiff};
+pub use editor::WordEditor;
+#[cfg(feature = "alloc")]
+pub use explain::explain;
+#[cfg(feature = "alloc")]
+pub use freshness::FreshnessTracker;
+pub use gear::GearState;
+#[cfg(feature = "alloc")]
+pub use hex::word_to_hex;
+pub use hex::{decode_hex, word_from_hex, word_to_hex_buf};
+#[cfg(feature = "alloc")]
+pub use icd::{check_capture, ConformanceReport, Icd, IcdEntry, RangeViolation, RateViolation};
+pub use inject::{inject_burst, inject_error};
+#[cfg(feature = "alloc")]
+pub use iso5::{iso5_string, RECORD_SEPARATOR};
+pub use param::{decode_param, encode_param, Parameter};
+#[cfg(feature = "alloc")]
+pub use pattern::{is_bite_ramp, test_pattern, TestPattern};
+pub use position::{combine_position, position_pairs, POS_COARSE_RESOLUTION, POS_FINE_RESOLUTION};
+#[cfg(feature = "alloc")]
+pub use rate::RateMonitor;
+#[cfg(feature = "std")]
+pub use reader::WordReader;
+pub use stats::BnrStats;
+#[cfg(feature = "alloc")]
+pub use table::{decode_for_equipment, LabelTable};
+pub use table::{EquipmentId, LabelSpec};
+#[cfg(feature = "test-util")]
+pub use test_util::assert_roundtrip;
+pub use timing::{bus_utilization, word_duration, BitRate};
+pub use units::Unit;
+#[cfg(feature = "alloc")]
+pub use validate::{validate, ValidationWarning};
+pub use view::WordView;
 
 /// Errors that can occur during ARINC 429 operations.
-#[derive(Error, Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq)]
+#[cfg_attr(
+    all(feature = "thiserror", not(feature = "minimal-error")),
+    derive(thiserror::Error)
+)]
 pub enum ArincError {
     /// Data field exceeds 19 bits (max allowed value: 524287)
-    #[error("Data exceeds 19 bits: {0}")]
+    #[cfg_attr(
+        all(feature = "thiserror", not(feature = "minimal-error")),
+        error("Data exceeds 19 bits: {0}")
+    )]
     DataOverflow(u32),
 
     /// Source/Destination Identifier must be 0–3
-    #[error("SDI must be 0-3: {0}")]
+    #[cfg_attr(
+        all(feature = "thiserror", not(feature = "minimal-error")),
+        error("SDI must be 0-3: {0}")
+    )]
     InvalidSdi(u8),
 
     /// Sign/Status Matrix must be 0–3
-    #[error("SSM must be 0-3: {0}")]
+    #[cfg_attr(
+        all(feature = "thiserror", not(feature = "minimal-error")),
+        error("SSM must be 0-3: {0}")
+    )]
     InvalidSsm(u8),
 
     /// Odd parity check failed
-    #[error("Parity check failed")]
+    #[cfg_attr(
+        all(feature = "thiserror", not(feature = "minimal-error")),
+        error("Parity check failed")
+    )]
     ParityMismatch,
 
     /// Invalid octal label string (e.g., contains non-octal digits or out of range)
-    #[error("Invalid octal label string")]
+    #[cfg_attr(
+        all(feature = "thiserror", not(feature = "minimal-error")),
+        error("Invalid octal label string (expected 1-3 octal digits, 0 to 377)")
+    )]
     InvalidOctalLabel,
+
+    /// Physical value cannot be represented in the label's BNR data field
+    #[cfg_attr(
+        all(feature = "thiserror", not(feature = "minimal-error")),
+        error("Value {value} out of range for label {label:?}")
+    )]
+    ValueOutOfRange { label: Label, value: f64 },
+
+    /// Label has no BNR physical interpretation (BCD or unknown)
+    #[cfg_attr(
+        all(feature = "thiserror", not(feature = "minimal-error")),
+        error("Label {0:?} has no physical encoding")
+    )]
+    UnsupportedLabel(Label),
+
+    /// Byte buffer ended with fewer than 4 bytes left (a partial word)
+    #[cfg_attr(
+        all(feature = "thiserror", not(feature = "minimal-error")),
+        error("Truncated buffer: {remaining} trailing bytes")
+    )]
+    TruncatedBuffer { remaining: usize },
+
+    /// Value cannot be represented as BCD in the label's digit layout
+    #[cfg_attr(
+        all(feature = "thiserror", not(feature = "minimal-error")),
+        error("Invalid BCD value")
+    )]
+    InvalidBcd,
+
+    /// File-transfer word sequence is malformed
+    #[cfg_attr(
+        all(feature = "thiserror", not(feature = "minimal-error")),
+        error("Invalid file transfer: {0}")
+    )]
+    InvalidTransfer(&'static str),
+
+    /// Hex word string is not exactly 8 hex digits (optionally prefixed with `0x`)
+    #[cfg_attr(
+        all(feature = "thiserror", not(feature = "minimal-error")),
+        error("Invalid hex word")
+    )]
+    InvalidHex,
+
+    /// Word carries a different label from the one being decoded
+    #[cfg_attr(
+        all(feature = "thiserror", not(feature = "minimal-error")),
+        error("Expected label {expected:03o}, found {found:03o}")
+    )]
+    LabelMismatch { expected: u8, found: u8 },
+}
+
+// Keep these messages in step with the `#[error]` attributes above.
+#[cfg(not(all(feature = "thiserror", not(feature = "minimal-error"))))]
+impl fmt::Display for ArincError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArincError::DataOverflow(data) => write!(f, "Data exceeds 19 bits: {}", data),
+            ArincError::InvalidSdi(sdi) => write!(f, "SDI must be 0-3: {}", sdi),
+            ArincError::InvalidSsm(ssm) => write!(f, "SSM must be 0-3: {}", ssm),
+            ArincError::ParityMismatch => f.write_str("Parity check failed"),
+            ArincError::InvalidOctalLabel => {
+                f.write_str("Invalid octal label string (expected 1-3 octal digits, 0 to 377)")
+            }
+            ArincError::ValueOutOfRange { label, value } => {
+                write!(f, "Value {} out of range for label {:?}", value, label)
+            }
+            ArincError::UnsupportedLabel(label) => {
+                write!(f, "Label {:?} has no physical encoding", label)
+            }
+            ArincError::TruncatedBuffer { remaining } => {
+                write!(f, "Truncated buffer: {} trailing bytes", remaining)
+            }
+            ArincError::InvalidBcd => f.write_str("Invalid BCD value"),
+            ArincError::InvalidTransfer(reason) => write!(f, "Invalid file transfer: {}", reason),
+            ArincError::InvalidHex => f.write_str("Invalid hex word"),
+            ArincError::LabelMismatch { expected, found } => {
+                write!(f, "Expected label {:03o}, found {:03o}", expected, found)
+            }
+        }
+    }
 }
 
+#[cfg(not(all(feature = "thiserror", not(feature = "minimal-error"))))]
+impl core::error::Error for ArincError {}
+
 /// Sign/Status Matrix (SSM) values as defined in ARINC 429.
 ///
 /// These indicate data validity and are common to both BNR and BCD data types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Ssm {
     /// Failure Warning – equipment failure detected
     FailureWarning,
@@ -76,7 +276,7 @@ pub enum Ssm {
 
 impl Ssm {
     /// Convert raw SSM bits (0–3) to the corresponding enum variant.
-    pub fn from_u8(value: u8) -> Self {
+    pub const fn from_u8(value: u8) -> Self {
         match value {
             0 => Self::FailureWarning,
             1 => Self::NoComputedData,
@@ -86,6 +286,16 @@ impl Ssm {
         }
     }
 
+    /// Raw SSM bits (0–3) for use with [`encode`].
+    pub const fn as_u8(&self) -> u8 {
+        match self {
+            Self::FailureWarning => 0,
+            Self::NoComputedData => 1,
+            Self::FunctionalTest => 2,
+            Self::NormalOperation => 3,
+        }
+    }
+
     /// Human-readable description of the SSM state.
     pub fn name(&self) -> &'static str {
         match self {
@@ -95,6 +305,154 @@ impl Ssm {
             Self::NormalOperation => "Normal Operation",
         }
     }
+
+    /// Display severity of the SSM state, e.g. for choosing a colour in a UI.
+    pub const fn severity(&self) -> Severity {
+        match self {
+            Self::NormalOperation => Severity::Ok,
+            Self::FunctionalTest => Severity::Info,
+            Self::NoComputedData => Severity::Warning,
+            Self::FailureWarning => Severity::Error,
+        }
+    }
+
+    /// Position in the data-validity ranking used by [`Ord`]; higher is more trustworthy.
+    fn validity(&self) -> u8 {
+        match self {
+            Self::FailureWarning => 0,
+            Self::NoComputedData => 1,
+            Self::FunctionalTest => 2,
+            Self::NormalOperation => 3,
+        }
+    }
+}
+
+/// Orders by data validity: `NormalOperation > FunctionalTest > NoComputedData >
+/// FailureWarning`, for choosing between redundant sources. The ranking is defined
+/// explicitly and does not depend on the raw SSM bit values.
+impl Ord for Ssm {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.validity().cmp(&other.validity())
+    }
+}
+
+impl PartialOrd for Ssm {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// How prominently a UI should flag a word's status; see [`Ssm::severity`].
+///
+/// Ordered from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+    /// Valid data
+    Ok,
+    /// Test data, not for use
+    Info,
+    /// No data available
+    Warning,
+    /// Source has failed
+    Error,
+}
+
+/// Sign/Status Matrix values as defined for BCD data words.
+///
+/// BCD words reuse the SSM bits as sign plus status, so the same raw bits mean something
+/// different than for BNR words (see [`Ssm`]). Use [`ArincWord::bcd_ssm`] to interpret a
+/// decoded BCD word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BcdSsm {
+    /// Plus, North, East, Right, To, Above – data is valid and positive
+    Plus,
+    /// No Computed Data – data not available or invalid
+    NoComputedData,
+    /// Functional Test – self-test in progress
+    FunctionalTest,
+    /// Minus, South, West, Left, From, Below – data is valid and negative
+    Minus,
+}
+
+impl BcdSsm {
+    /// Convert raw SSM bits (0–3) to the corresponding BCD variant.
+    pub fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Plus,
+            1 => Self::NoComputedData,
+            2 => Self::FunctionalTest,
+            3 => Self::Minus,
+            _ => Self::NoComputedData, // Invalid values treated as NCD
+        }
+    }
+
+    /// Human-readable description of the SSM state.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Plus => "Plus",
+            Self::NoComputedData => "No Computed Data",
+            Self::FunctionalTest => "Functional Test",
+            Self::Minus => "Minus",
+        }
+    }
+}
+
+/// Source/Destination Identifier (bits 9–10) as a typed value.
+///
+/// SDI `00` is ambiguous: for labels that use SDI to address one of several receivers
+/// it means "all call", for others it simply identifies installation 0 (or SDI is not
+/// used at all). Which applies is label- and installation-dependent, so this crate
+/// leaves the interpretation of [`Sdi::All`] to the caller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Sdi {
+    /// `00` – all call, or installation 0 (label-dependent)
+    All,
+    /// `01` – installation/receiver 1
+    One,
+    /// `10` – installation/receiver 2
+    Two,
+    /// `11` – installation/receiver 3
+    Three,
+}
+
+impl Sdi {
+    /// Convert raw SDI bits (0–3) to the corresponding variant.
+    ///
+    /// Returns [`ArincError::InvalidSdi`] for values above 3.
+    pub fn from_u8(value: u8) -> Result<Self, ArincError> {
+        match value {
+            0 => Ok(Self::All),
+            1 => Ok(Self::One),
+            2 => Ok(Self::Two),
+            3 => Ok(Self::Three),
+            _ => Err(ArincError::InvalidSdi(value)),
+        }
+    }
+
+    /// Raw SDI bits (0–3) for use with [`encode`].
+    pub const fn as_u8(&self) -> u8 {
+        match self {
+            Self::All => 0,
+            Self::One => 1,
+            Self::Two => 2,
+            Self::Three => 3,
+        }
+    }
+}
+
+/// Encoding of a label's 19-bit data field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DataType {
+    /// Binary (two's complement) numeric data – see [`ArincWord::to_physical`]
+    Bnr,
+    /// Binary-coded decimal data – see [`ArincWord::to_date`]/[`ArincWord::to_time`]
+    Bcd,
+    /// Discrete bit-packed data, or data of unknown format
+    Discrete,
 }
 
 /// Known ARINC 429 parameter labels supported by this crate.
@@ -102,6 +460,7 @@ impl Ssm {
 /// Each variant includes its standard octal and decimal code, data type (BNR/BCD),
 /// and physical interpretation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Label {
     /// Ground Speed – label 012 (decimal 10), BNR, resolution 0.125 knots
     GroundSpeed,
@@ -121,37 +480,655 @@ pub enum Label {
     Date,
     /// Roll Angle – label 324 (decimal 212), BNR signed, resolution 0.01°
     RollAngle,
+    /// Vertical Speed – label 212 (decimal 138), BNR signed, 11 bits, resolution 16 ft/min
+    VerticalSpeed,
+    /// True Heading – label 314 (decimal 204), BNR signed, resolution 0.01°, 0–360°
+    TrueHeading,
+    /// Magnetic Heading – label 320 (decimal 208), BNR signed, resolution 0.01°, 0–360°
+    MagneticHeading,
+    /// Pitch Angle – label 325 (decimal 213), BNR signed, resolution 0.01°
+    PitchAngle,
+    /// Flight Path Angle – label 322 (decimal 210), BNR signed, resolution 0.01°
+    FlightPathAngle,
+    /// Angle of Attack – label 241 (decimal 161), BNR signed, resolution 0.05°
+    AngleOfAttack,
+    /// Sideslip Angle – label 226 (decimal 150), BNR signed, resolution 0.05°
+    SideslipAngle,
+    /// Present Position Latitude (coarse) – label 310 (decimal 200), BNR signed, resolution 0.04°
+    LatitudeCoarse,
+    /// Present Position Longitude (coarse) – label 311 (decimal 201), BNR signed, resolution 0.04°
+    LongitudeCoarse,
+    /// Present Position Latitude (fine) – label 312 (decimal 202), BNR, fraction of one coarse LSB
+    LatitudeFine,
+    /// Present Position Longitude (fine) – label 313 (decimal 203), BNR, fraction of one coarse LSB
+    LongitudeFine,
+    /// Equipment Identification – label 377 (decimal 255), discrete, 3 hex digits in bits 11–22
+    EquipmentIdentification,
+    /// Selected Heading – label 101 (decimal 65), BNR signed, resolution 0.01°, 0–360°
+    SelectedHeading,
+    /// Selected Altitude – label 102 (decimal 66), BNR signed, resolution 1 ft
+    SelectedAltitude,
+    /// Static Air Temperature (SAT) – label 213 (decimal 139), BNR signed, resolution 0.25 °C
+    StaticAirTemperature,
+    /// Indicated Airspeed – label 206 (decimal 134), BNR, resolution 0.0625 knots
+    IndicatedAirspeed,
+    /// Engine N2 (core speed) – label 344 (decimal 228), BNR, resolution 0.0625 % RPM
+    EngineN2,
+    /// Exhaust Gas Temperature (EGT) – label 345 (decimal 229), BNR signed, resolution 0.5 °C
+    Egt,
+    /// Engine N1 (fan speed) – label 346 (decimal 230), BNR, resolution 0.0625 % RPM
+    EngineN1,
+    /// DME Distance – label 202 (decimal 130), BNR, resolution 0.01 NM
+    DmeDistance,
+    /// Localizer Deviation – label 173 (decimal 123), BNR signed, resolution 0.0001 DDM, positive = fly left
+    LocalizerDeviation,
+    /// Glideslope Deviation – label 174 (decimal 124), BNR signed, resolution 0.0001 DDM, positive = fly down
+    GlideslopeDeviation,
+    /// Radio Altitude – label 164 (decimal 116), BNR signed, 17 bits, resolution 0.125 ft, ±8192 ft
+    RadioAltitude,
+    /// Wind Speed – label 315 (decimal 205), BNR, 8 bits, resolution 1 knot
+    WindSpeed,
+    /// Wind Direction (true) – label 316 (decimal 206), BNR signed, resolution 0.01°, 0–360°
+    WindDirection,
+    /// Fuel Flow – label 244 (decimal 164), BNR, 15 bits, resolution 1 lb/h
+    FuelFlow,
+    /// Total Fuel Quantity – label 247 (decimal 167), BNR, 15 bits, resolution 20 lb
+    FuelQuantity,
+    /// Landing Gear Position – label 272 (decimal 186), discrete, see [`ArincWord::gear_state`]
+    GearPosition,
+    /// Flap/Slat Position – label 273 (decimal 187), discrete, one bit per airframe-specific detent
+    FlapPosition,
+    /// GNSS Latitude – label 110 (decimal 72), BNR signed, 21 bits including SDI bits 9–10, resolution 180/2^20 °
+    GnssLatitude,
+    /// GNSS Longitude – label 111 (decimal 73), BNR signed, 21 bits including SDI bits 9–10, resolution 180/2^20 °
+    GnssLongitude,
     /// Unknown or unsupported label
     Unknown(u8),
 }
 
+/// Description of one named label: everything the [`Label`] accessors report.
+struct LabelInfo {
+    label: Label,
+    name: &'static str,
+    units: &'static str,
+    data_type: DataType,
+    /// BNR labels only
+    resolution: Option<f64>,
+    /// BNR labels only; for signed labels the sign bit is the most significant of these
+    significant_bits: Option<u8>,
+    signed: bool,
+    /// Whether ARINC bits 9–10 are the two least significant data bits rather than an SDI
+    sdi_is_data: bool,
+    /// Whether the BNR significant bits are Gray-coded
+    gray: bool,
+    /// Nominal `(min, max)` transmit interval in microseconds
+    interval_us: (u64, u64),
+}
+
+const SIGNED: bool = true;
+const UNSIGNED: bool = false;
+
+impl LabelInfo {
+    /// A non-BNR label without units or scaling.
+    const fn new(
+        label: Label,
+        name: &'static str,
+        data_type: DataType,
+        interval_us: (u64, u64),
+    ) -> Self {
+        Self {
+            label,
+            name,
+            units: "",
+            data_type,
+            resolution: None,
+            significant_bits: None,
+            signed: false,
+            sdi_is_data: false,
+            gray: false,
+            interval_us,
+        }
+    }
+
+    const fn bnr(
+        label: Label,
+        name: &'static str,
+        units: &'static str,
+        resolution: f64,
+        significant_bits: u8,
+        signed: bool,
+        interval_us: (u64, u64),
+    ) -> Self {
+        assert!(significant_bits >= 1 && significant_bits <= 21);
+        Self {
+            label,
+            name,
+            units,
+            data_type: DataType::Bnr,
+            resolution: Some(resolution),
+            significant_bits: Some(significant_bits),
+            signed,
+            sdi_is_data: false,
+            gray: false,
+            interval_us,
+        }
+    }
+
+    /// This row with the SDI bits as the low data bits.
+    const fn sdi_as_data(mut self) -> Self {
+        self.sdi_is_data = true;
+        self
+    }
+
+    /// This row with a Gray-coded data field.
+    #[cfg(test)]
+    const fn gray_coded(mut self) -> Self {
+        self.gray = true;
+        self
+    }
+
+    /// Scaling for this row's label, as returned by [`Label::spec`].
+    fn spec(&self, heading: bool) -> LabelSpec {
+        LabelSpec {
+            name: self.name,
+            units: self.units,
+            resolution: self.resolution.unwrap_or(1.0),
+            signed: self.signed,
+            significant_bits: self.significant_bits.unwrap_or(19),
+            data_type: self.data_type,
+            sign_in_ssm: false,
+            gray: self.gray,
+            sdi_is_data: self.sdi_is_data,
+            heading,
+        }
+    }
+}
+
+/// Every named label, in declaration order. Label codes come from [`Label::raw`];
+/// [`Label::from_u8`] is derived from them so the two cannot disagree.
+const LABEL_INFO: &[LabelInfo] = &[
+    LabelInfo::bnr(
+        Label::GroundSpeed,
+        "Ground Speed",
+        "knots",
+        0.125,
+        19,
+        UNSIGNED,
+        (25_000, 50_000),
+    ),
+    LabelInfo::new(
+        Label::UtcTime,
+        "UTC Time",
+        DataType::Bcd,
+        (100_000, 200_000),
+    ),
+    LabelInfo::bnr(
+        Label::PressureAltitude,
+        "Pressure Altitude (1013.25 mb)",
+        "feet",
+        1.0,
+        19,
+        SIGNED,
+        (31_250, 62_500),
+    ),
+    LabelInfo::bnr(
+        Label::BaroCorrectedAlt,
+        "Baro-Corrected Altitude",
+        "feet",
+        1.0,
+        19,
+        SIGNED,
+        (31_250, 62_500),
+    ),
+    LabelInfo::bnr(
+        Label::Mach,
+        "Mach",
+        "",
+        0.001,
+        19,
+        UNSIGNED,
+        (62_500, 125_000),
+    ),
+    LabelInfo::bnr(
+        Label::TrueAirspeed,
+        "True Airspeed",
+        "knots",
+        1.0,
+        19,
+        UNSIGNED,
+        (62_500, 125_000),
+    ),
+    LabelInfo::bnr(
+        Label::Tat,
+        "Total Air Temperature (TAT)",
+        "°C",
+        0.25,
+        19,
+        SIGNED,
+        (250_000, 500_000),
+    ),
+    LabelInfo::new(Label::Date, "Date", DataType::Bcd, (500_000, 1_000_000)),
+    LabelInfo::bnr(
+        Label::RollAngle,
+        "Roll Angle",
+        "°",
+        0.01,
+        19,
+        SIGNED,
+        (25_000, 50_000),
+    ),
+    LabelInfo::bnr(
+        Label::VerticalSpeed,
+        "Vertical Speed",
+        "ft/min",
+        16.0,
+        11,
+        SIGNED,
+        (31_250, 62_500),
+    ),
+    LabelInfo::bnr(
+        Label::TrueHeading,
+        "True Heading",
+        "°",
+        0.01,
+        19,
+        SIGNED,
+        (25_000, 50_000),
+    ),
+    LabelInfo::bnr(
+        Label::MagneticHeading,
+        "Magnetic Heading",
+        "°",
+        0.01,
+        19,
+        SIGNED,
+        (25_000, 50_000),
+    ),
+    LabelInfo::bnr(
+        Label::PitchAngle,
+        "Pitch Angle",
+        "°",
+        0.01,
+        19,
+        SIGNED,
+        (25_000, 50_000),
+    ),
+    LabelInfo::bnr(
+        Label::FlightPathAngle,
+        "Flight Path Angle",
+        "°",
+        0.01,
+        19,
+        SIGNED,
+        (25_000, 50_000),
+    ),
+    LabelInfo::bnr(
+        Label::AngleOfAttack,
+        "Angle of Attack",
+        "°",
+        0.05,
+        19,
+        SIGNED,
+        (62_500, 125_000),
+    ),
+    LabelInfo::bnr(
+        Label::SideslipAngle,
+        "Sideslip Angle",
+        "°",
+        0.05,
+        19,
+        SIGNED,
+        (62_500, 125_000),
+    ),
+    LabelInfo::bnr(
+        Label::LatitudeCoarse,
+        "Latitude (Coarse)",
+        "°",
+        POS_COARSE_RESOLUTION,
+        19,
+        SIGNED,
+        (100_000, 200_000),
+    ),
+    LabelInfo::bnr(
+        Label::LongitudeCoarse,
+        "Longitude (Coarse)",
+        "°",
+        POS_COARSE_RESOLUTION,
+        19,
+        SIGNED,
+        (100_000, 200_000),
+    ),
+    LabelInfo::bnr(
+        Label::LatitudeFine,
+        "Latitude (Fine)",
+        "°",
+        POS_FINE_RESOLUTION,
+        19,
+        UNSIGNED,
+        (100_000, 200_000),
+    ),
+    LabelInfo::bnr(
+        Label::LongitudeFine,
+        "Longitude (Fine)",
+        "°",
+        POS_FINE_RESOLUTION,
+        19,
+        UNSIGNED,
+        (100_000, 200_000),
+    ),
+    LabelInfo::new(
+        Label::EquipmentIdentification,
+        "Equipment Identification",
+        DataType::Discrete,
+        (500_000, 1_000_000),
+    ),
+    LabelInfo::bnr(
+        Label::SelectedHeading,
+        "Selected Heading",
+        "°",
+        0.01,
+        19,
+        SIGNED,
+        (100_000, 200_000),
+    ),
+    LabelInfo::bnr(
+        Label::SelectedAltitude,
+        "Selected Altitude",
+        "feet",
+        1.0,
+        19,
+        SIGNED,
+        (100_000, 200_000),
+    ),
+    LabelInfo::bnr(
+        Label::StaticAirTemperature,
+        "Static Air Temperature (SAT)",
+        "°C",
+        0.25,
+        19,
+        SIGNED,
+        (250_000, 500_000),
+    ),
+    LabelInfo::bnr(
+        Label::IndicatedAirspeed,
+        "Indicated Airspeed",
+        "knots",
+        0.0625,
+        19,
+        UNSIGNED,
+        (62_500, 125_000),
+    ),
+    LabelInfo::bnr(
+        Label::EngineN2,
+        "Engine N2",
+        "% RPM",
+        0.0625,
+        19,
+        UNSIGNED,
+        (62_500, 125_000),
+    ),
+    LabelInfo::bnr(
+        Label::Egt,
+        "Exhaust Gas Temperature (EGT)",
+        "°C",
+        0.5,
+        19,
+        SIGNED,
+        (62_500, 125_000),
+    ),
+    LabelInfo::bnr(
+        Label::EngineN1,
+        "Engine N1",
+        "% RPM",
+        0.0625,
+        19,
+        UNSIGNED,
+        (62_500, 125_000),
+    ),
+    LabelInfo::bnr(
+        Label::DmeDistance,
+        "DME Distance",
+        "NM",
+        0.01,
+        19,
+        UNSIGNED,
+        (100_000, 200_000),
+    ),
+    LabelInfo::bnr(
+        Label::LocalizerDeviation,
+        "Localizer Deviation",
+        "DDM",
+        0.0001,
+        19,
+        SIGNED,
+        (31_250, 62_500),
+    ),
+    LabelInfo::bnr(
+        Label::GlideslopeDeviation,
+        "Glideslope Deviation",
+        "DDM",
+        0.0001,
+        19,
+        SIGNED,
+        (31_250, 62_500),
+    ),
+    LabelInfo::bnr(
+        Label::RadioAltitude,
+        "Radio Altitude",
+        "feet",
+        0.125,
+        17,
+        SIGNED,
+        (25_000, 50_000),
+    ),
+    LabelInfo::bnr(
+        Label::WindSpeed,
+        "Wind Speed",
+        "knots",
+        1.0,
+        8,
+        UNSIGNED,
+        (50_000, 100_000),
+    ),
+    LabelInfo::bnr(
+        Label::WindDirection,
+        "Wind Direction",
+        "°",
+        0.01,
+        19,
+        SIGNED,
+        (50_000, 100_000),
+    ),
+    LabelInfo::bnr(
+        Label::FuelFlow,
+        "Fuel Flow",
+        "lb/h",
+        1.0,
+        15,
+        UNSIGNED,
+        (100_000, 200_000),
+    ),
+    LabelInfo::bnr(
+        Label::FuelQuantity,
+        "Total Fuel Quantity",
+        "lb",
+        20.0,
+        15,
+        UNSIGNED,
+        (500_000, 1_000_000),
+    ),
+    LabelInfo::new(
+        Label::GearPosition,
+        "Landing Gear Position",
+        DataType::Discrete,
+        (100_000, 200_000),
+    ),
+    LabelInfo::new(
+        Label::FlapPosition,
+        "Flap/Slat Position",
+        DataType::Discrete,
+        (100_000, 200_000),
+    ),
+    LabelInfo::bnr(
+        Label::GnssLatitude,
+        "GNSS Latitude",
+        "°",
+        180.0 / 1_048_576.0,
+        21,
+        SIGNED,
+        (200_000, 1_200_000),
+    )
+    .sdi_as_data(),
+    LabelInfo::bnr(
+        Label::GnssLongitude,
+        "GNSS Longitude",
+        "°",
+        180.0 / 1_048_576.0,
+        21,
+        SIGNED,
+        (200_000, 1_200_000),
+    )
+    .sdi_as_data(),
+];
+
+/// Marks a code in [`INFO_BY_CODE`] that has no named label.
+const NO_INFO: u8 = u8::MAX;
+
+/// Index into [`LABEL_INFO`] for each label code.
+const INFO_BY_CODE: [u8; 256] = {
+    let mut by_code = [NO_INFO; 256];
+    let mut i = 0;
+    while i < LABEL_INFO.len() {
+        let code = LABEL_INFO[i].label.raw() as usize;
+        assert!(by_code[code] == NO_INFO, "two labels share a code");
+        by_code[code] = i as u8;
+        i += 1;
+    }
+    by_code
+};
+
+/// Codes whose label uses ARINC bits 9–10 as data, so [`decode`] need not look up the
+/// label's full info per word.
+pub(crate) const SDI_AS_DATA: [bool; 256] = {
+    let mut by_code = [false; 256];
+    let mut i = 0;
+    while i < LABEL_INFO.len() {
+        by_code[LABEL_INFO[i].label.raw() as usize] = LABEL_INFO[i].sdi_is_data;
+        i += 1;
+    }
+    by_code
+};
+
+/// Every named (non-[`Label::Unknown`]) label.
+const KNOWN_LABELS: [Label; LABEL_INFO.len()] = {
+    let mut labels = [Label::Unknown(0); LABEL_INFO.len()];
+    let mut i = 0;
+    while i < LABEL_INFO.len() {
+        labels[i] = LABEL_INFO[i].label;
+        i += 1;
+    }
+    labels
+};
+
 impl Label {
-    /// Convert a raw decimal label code (after bit reversal) to the enum variant.
-    pub fn from_u8(raw: u8) -> Self {
-        match raw {
-            10 => Label::GroundSpeed,
-            104 => Label::UtcTime,
-            131 => Label::PressureAltitude,
-            132 => Label::BaroCorrectedAlt,
-            133 => Label::Mach,
-            136 => Label::TrueAirspeed,
-            137 => Label::Tat,
-            176 => Label::Date,
-            212 => Label::RollAngle,
-            _ => Label::Unknown(raw),
+    /// Every named label this crate knows, i.e. all variants except [`Label::Unknown`].
+    ///
+    /// ```rust
+    /// use arinc429::Label;
+    ///
+    /// assert!(Label::all().contains(&Label::GroundSpeed));
+    /// assert!(!Label::all().iter().any(|l| matches!(l, Label::Unknown(_))));
+    /// ```
+    pub const fn all() -> &'static [Label] {
+        &KNOWN_LABELS
+    }
+
+    /// Convert a raw label code (after bit reversal) to the enum variant.
+    ///
+    /// The code is a plain number, so write it as a Rust octal literal: `0o203`, not
+    /// `203`. To use the octal digits as printed on an ICD, see
+    /// [`from_octal_code`](Self::from_octal_code) or
+    /// [`from_octal_str`](Self::from_octal_str).
+    pub const fn from_u8(raw: u8) -> Self {
+        match INFO_BY_CODE[raw as usize] {
+            NO_INFO => Label::Unknown(raw),
+            index => LABEL_INFO[index as usize].label,
         }
     }
 
     /// Parse an octal label string (e.g., `"012"`, `"203"`) into the corresponding variant.
     ///
-    /// Returns an error if the string is not valid octal or maps to an unknown label.
+    /// Any code from `"0"` to `"377"` is accepted; codes without a variant map to
+    /// [`Label::Unknown`].
+    ///
+    /// # Errors
+    /// [`ArincError::InvalidOctalLabel`] unless `s` is 1–3 octal digits with a value of
+    /// at most `377`. Signs and surrounding whitespace are rejected.
     pub fn from_octal_str(s: &str) -> Result<Self, ArincError> {
-        let decimal = u8::from_str_radix(s, 8).map_err(|_| ArincError::InvalidOctalLabel)?;
-        Ok(Self::from_u8(decimal))
+        if s.is_empty() || s.len() > 3 {
+            return Err(ArincError::InvalidOctalLabel);
+        }
+        let mut value = 0u16;
+        for digit in s.bytes() {
+            if !(b'0'..=b'7').contains(&digit) {
+                return Err(ArincError::InvalidOctalLabel);
+            }
+            value = value * 8 + u16::from(digit - b'0');
+        }
+        let raw = u8::try_from(value).map_err(|_| ArincError::InvalidOctalLabel)?;
+        Ok(Self::from_u8(raw))
+    }
+
+    /// Look up a label from its octal digits written as a decimal number, the way label
+    /// numbers appear in ICDs and on screen: `from_octal_code(203)` is Pressure Altitude.
+    ///
+    /// Labels have three representations:
+    ///
+    /// | Representation | Pressure Altitude | API |
+    /// |---|---|---|
+    /// | Octal digits | `"203"` / `203` | [`from_octal_str`](Self::from_octal_str), `from_octal_code` |
+    /// | Label code | `0o203` (131) | [`from_u8`](Self::from_u8), [`raw`](Self::raw), [`encode`] |
+    /// | Wire byte, bit-reversed | `0b1100_0001` (193) | bits 1–8 of the transmitted word |
+    ///
+    /// Do not pass a Rust octal literal here: `from_octal_code(0o203)` reads the digits
+    /// of 131 and returns label 131, not 203.
+    ///
+    /// ```rust
+    /// use arinc429::Label;
+    ///
+    /// assert_eq!(Label::from_octal_code(203), Ok(Label::PressureAltitude));
+    /// assert_eq!(Label::from_u8(0o203), Label::PressureAltitude);
+    /// assert_eq!(Label::PressureAltitude.octal_code(), 203);
+    /// ```
+    ///
+    /// # Errors
+    /// [`ArincError::InvalidOctalLabel`] if `code` contains the digit 8 or 9, or is
+    /// greater than 377.
+    pub const fn from_octal_code(code: u16) -> Result<Self, ArincError> {
+        if code > 377 {
+            return Err(ArincError::InvalidOctalLabel);
+        }
+        let digits = [code / 100, code / 10 % 10, code % 10];
+        if digits[0] > 7 || digits[1] > 7 || digits[2] > 7 {
+            return Err(ArincError::InvalidOctalLabel);
+        }
+        Ok(Self::from_u8(
+            (digits[0] * 64 + digits[1] * 8 + digits[2]) as u8,
+        ))
+    }
+
+    /// The label's octal digits as a decimal number, the inverse of
+    /// [`from_octal_code`](Self::from_octal_code): `203` for Pressure Altitude.
+    pub const fn octal_code(&self) -> u16 {
+        let raw = self.raw() as u16;
+        raw / 64 * 100 + raw / 8 % 8 * 10 + raw % 8
     }
 
     /// Raw decimal label code for use with [`encode`].
-    pub fn raw(&self) -> u8 {
+    ///
+    /// This is the one place label codes are assigned; every other accessor looks the
+    /// label up by its code.
+    pub const fn raw(&self) -> u8 {
         match self {
             Label::GroundSpeed => 10,
             Label::UtcTime => 104,
@@ -162,64 +1139,248 @@ impl Label {
             Label::Tat => 137,
             Label::Date => 176,
             Label::RollAngle => 212,
+            Label::VerticalSpeed => 138,
+            Label::TrueHeading => 204,
+            Label::MagneticHeading => 208,
+            Label::PitchAngle => 213,
+            Label::FlightPathAngle => 210,
+            Label::AngleOfAttack => 161,
+            Label::SideslipAngle => 150,
+            Label::LatitudeCoarse => 200,
+            Label::LongitudeCoarse => 201,
+            Label::LatitudeFine => 202,
+            Label::LongitudeFine => 203,
+            Label::EquipmentIdentification => 255,
+            Label::SelectedHeading => 65,
+            Label::SelectedAltitude => 66,
+            Label::StaticAirTemperature => 139,
+            Label::IndicatedAirspeed => 134,
+            Label::EngineN2 => 228,
+            Label::Egt => 229,
+            Label::EngineN1 => 230,
+            Label::DmeDistance => 130,
+            Label::LocalizerDeviation => 123,
+            Label::GlideslopeDeviation => 124,
+            Label::RadioAltitude => 116,
+            Label::WindSpeed => 205,
+            Label::WindDirection => 206,
+            Label::FuelFlow => 164,
+            Label::FuelQuantity => 167,
+            Label::GearPosition => 186,
+            Label::FlapPosition => 187,
+            Label::GnssLatitude => 72,
+            Label::GnssLongitude => 73,
             Label::Unknown(n) => *n,
         }
     }
 
+    /// The 3-digit zero-padded octal representation as ASCII bytes, without allocating.
+    ///
+    /// ```rust
+    /// use arinc429::Label;
+    ///
+    /// assert_eq!(&Label::GroundSpeed.octal_bytes(), b"012");
+    /// ```
+    pub const fn octal_bytes(&self) -> [u8; 3] {
+        let code = self.raw();
+        [
+            b'0' + (code >> 6),
+            b'0' + ((code >> 3) & 7),
+            b'0' + (code & 7),
+        ]
+    }
+
+    /// Write the 3-digit zero-padded octal representation into `buf` without allocating.
+    ///
+    /// ```rust
+    /// use arinc429::Label;
+    ///
+    /// let mut buf = [0u8; 3];
+    /// assert_eq!(Label::PressureAltitude.octal_to(&mut buf), "203");
+    /// ```
+    pub fn octal_to<'a>(&self, buf: &'a mut [u8; 3]) -> &'a str {
+        *buf = self.octal_bytes();
+        core::str::from_utf8(buf).expect("octal digits are ASCII")
+    }
+
+    /// Write the 3-digit zero-padded octal representation to any [`fmt::Write`] sink.
+    pub fn write_octal(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        f.write_str(self.octal_to(&mut [0; 3]))
+    }
+
     /// Standard octal representation (3 digits, zero-padded).
+    #[cfg(feature = "alloc")]
     pub fn octal(&self) -> String {
-        match self {
-            Label::GroundSpeed => "012".to_string(),
-            Label::UtcTime => "150".to_string(),
-            Label::PressureAltitude => "203".to_string(),
-            Label::BaroCorrectedAlt => "204".to_string(),
-            Label::Mach => "205".to_string(),
-            Label::Tat => "211".to_string(),
-            Label::TrueAirspeed => "210".to_string(),
-            Label::Date => "260".to_string(),
-            Label::RollAngle => "324".to_string(),
-            Label::Unknown(n) => format!("{:03o}", n),
+        self.octal_to(&mut [0; 3]).to_string()
+    }
+
+    /// Table entry for a named label, `None` for [`Label::Unknown`].
+    const fn info(&self) -> Option<&'static LabelInfo> {
+        if let Label::Unknown(_) = self {
+            return None;
+        }
+        match INFO_BY_CODE[self.raw() as usize] {
+            NO_INFO => None,
+            index => Some(&LABEL_INFO[index as usize]),
         }
     }
 
     /// Human-readable parameter name.
     pub fn name(&self) -> &'static str {
-        match self {
-            Label::GroundSpeed => "Ground Speed",
-            Label::UtcTime => "UTC Time",
-            Label::PressureAltitude => "Pressure Altitude (1013.25 mb)",
-            Label::BaroCorrectedAlt => "Baro-Corrected Altitude",
-            Label::Mach => "Mach",
-            Label::Tat => "Total Air Temperature (TAT)",
-            Label::TrueAirspeed => "True Airspeed",
-            Label::Date => "Date",
-            Label::RollAngle => "Roll Angle",
-            Label::Unknown(_) => "Unknown Label",
+        match self.info() {
+            Some(info) => info.name,
+            None => "Unknown Label",
         }
     }
 
     /// Physical units (empty string if none).
     pub fn units(&self) -> &'static str {
-        match self {
-            Label::GroundSpeed | Label::TrueAirspeed => "knots",
-            Label::PressureAltitude | Label::BaroCorrectedAlt => "feet",
-            Label::Mach => "",
-            Label::Tat => "°C",
-            Label::RollAngle => "°",
-            Label::Date | Label::UtcTime => "",
-            Label::Unknown(_) => "",
+        self.info().map_or("", |info| info.units)
+    }
+
+    /// Data field encoding for this label.
+    ///
+    /// Unknown labels are reported as [`DataType::Discrete`] since their format cannot be
+    /// inferred.
+    pub fn data_type(&self) -> DataType {
+        self.info()
+            .map_or(DataType::Discrete, |info| info.data_type)
+    }
+
+    /// Weight of the data field's least significant bit in physical units.
+    ///
+    /// Returns `None` for BCD and unknown labels.
+    pub fn resolution(&self) -> Option<f64> {
+        self.info()?.resolution
+    }
+
+    /// Full-scale `(min, max)` physical range of the label's BNR data field.
+    ///
+    /// Derived from [`Label::resolution`], the label's significant bits and its sign
+    /// convention. Returns `None` for BCD and unknown labels.
+    ///
+    /// Labels with a limited operating range use fewer significant bits so the range is
+    /// enforced here and by [`encode_physical`]: radio altitude is only meaningful close
+    /// to the ground and is limited to ±8192 ft.
+    pub fn range(&self) -> Option<(f64, f64)> {
+        let resolution = self.resolution()?;
+        let bits = self.significant_bits()?;
+        if self.is_signed() {
+            let half = (1u32 << (bits - 1)) as f64;
+            Some((-half * resolution, (half - 1.0) * resolution))
+        } else {
+            Some((0.0, field_mask(bits) as f64 * resolution))
+        }
+    }
+
+    /// Number of data field bits carrying the BNR value, counted from the field's LSB.
+    ///
+    /// For signed labels the sign bit is the most significant of these bits, so
+    /// [`ArincWord::to_physical`] sign-extends from bit `significant_bits - 1` of the data
+    /// field rather than from bit 18. Bits above them are ignored. Returns `None` for BCD
+    /// and unknown labels.
+    ///
+    /// ```rust
+    /// use arinc429::Label;
+    ///
+    /// assert_eq!(Label::PressureAltitude.significant_bits(), Some(19));
+    /// assert_eq!(Label::VerticalSpeed.significant_bits(), Some(11));
+    /// assert_eq!(Label::Date.significant_bits(), None);
+    /// ```
+    pub fn significant_bits(&self) -> Option<u8> {
+        self.info()?.significant_bits
+    }
+
+    /// Whether the BNR data field is two's complement signed.
+    fn is_signed(&self) -> bool {
+        self.info().is_some_and(|info| info.signed)
+    }
+
+    /// Whether the BNR data field is transmitted in Gray code rather than plain binary.
+    ///
+    /// [`ArincWord::to_physical`] converts such labels from Gray code before scaling. No
+    /// built-in label is Gray-coded at present; for equipment that Gray-codes a label,
+    /// define it in a [`LabelTable`] with [`LabelSpec::with_gray`] or decode it with
+    /// [`ArincWord::to_physical_gray`].
+    pub const fn is_gray_coded(&self) -> bool {
+        match self.info() {
+            Some(info) => info.gray,
+            None => false,
+        }
+    }
+
+    /// Whether ARINC bits 9–10 are the two least significant data bits rather than an
+    /// SDI.
+    ///
+    /// [`decode`] folds them into the low end of a 21-bit [`ArincWord::data`] and reports
+    /// an SDI of 0 for these labels, which need more than 19 bits of resolution. Of the
+    /// built-in labels this applies to GNSS latitude and longitude (labels 110/111).
+    ///
+    /// ```rust
+    /// use arinc429::Label;
+    ///
+    /// assert!(Label::GnssLatitude.sdi_is_data());
+    /// assert!(!Label::LatitudeCoarse.sdi_is_data());
+    /// ```
+    pub const fn sdi_is_data(&self) -> bool {
+        match self.info() {
+            Some(info) => info.sdi_is_data,
+            None => false,
         }
     }
+
+    /// Nominal `(min, max)` transmit interval of the label, per ARINC 429 Attachment 2.
+    ///
+    /// A transmitter should repeat the label no faster than `min` and no slower than
+    /// `max`, so a bus monitor can flag gaps between consecutive words outside that
+    /// window. Returns `None` for [`Label::Unknown`].
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use arinc429::Label;
+    ///
+    /// let (min, max) = Label::PressureAltitude.transmission_interval().unwrap();
+    /// let gap = Duration::from_millis(80);
+    /// assert!(!(min..=max).contains(&gap)); // arriving too slowly
+    /// ```
+    pub fn transmission_interval(&self) -> Option<(Duration, Duration)> {
+        let (min_us, max_us) = self.info()?.interval_us;
+        Some((Duration::from_micros(min_us), Duration::from_micros(max_us)))
+    }
+
+    /// Whether the label is a modular angle reported in `[0, 360)` degrees.
+    ///
+    /// Includes target values such as the selected heading, not just measured ones.
+    fn is_heading(&self) -> bool {
+        matches!(
+            self,
+            Label::TrueHeading
+                | Label::MagneticHeading
+                | Label::SelectedHeading
+                | Label::WindDirection
+        )
+    }
+
+    /// Full [`LabelSpec`] describing this label, or `None` for [`Label::Unknown`].
+    pub fn spec(&self) -> Option<LabelSpec> {
+        Some(self.info()?.spec(self.is_heading()))
+    }
 }
 
 /// A fully decoded ARINC 429 word.
+///
+/// With the `serde` feature, serialization also emits the label's octal string for
+/// readability; it is ignored when deserializing.
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub struct ArincWord {
     /// The parameter label
     pub label: Label,
-    /// Source/Destination Identifier (0–3)
+    /// Source/Destination Identifier (0–3); always 0 for labels that use bits 9–10 as
+    /// data (see [`Label::sdi_is_data`])
     pub sdi: u8,
-    /// Raw 19-bit data field
+    /// Raw data field: 19 bits, or 21 bits with ARINC bits 9–10 below them for labels
+    /// that use the SDI bits as data
     pub data: u32,
     /// Sign/Status Matrix
     pub ssm: Ssm,
@@ -228,104 +1389,466 @@ pub struct ArincWord {
 impl ArincWord {
     /// Convert the raw data to a physical value (e.g., knots, feet, °C) for supported BNR labels.
     ///
+    /// Heading labels are normalized into `[0, 360)` degrees.
+    ///
     /// Returns `None` if:
     /// - SSM is not Normal Operation
     /// - Label is not supported or is BCD (use `to_bcd_date`/`to_bcd_time` instead)
     pub fn to_physical(&self) -> Option<f64> {
-        if !matches!(self.ssm, Ssm::NormalOperation) {
+        if !self.label.spec()?.ssm_valid(self.ssm) {
+            return None;
+        }
+        self.to_physical_raw()
+    }
+
+    /// Like [`to_physical`](Self::to_physical), converted from the label's native unit
+    /// to `unit`.
+    ///
+    /// Returns `None` as `to_physical` does, or if `unit` measures a different quantity
+    /// from the label (e.g. [`Unit::Knots`] for an altitude, or any unit for an angle).
+    ///
+    /// ```rust
+    /// use arinc429::{decode, encode_physical, Label, Ssm, Unit};
+    ///
+    /// let word = encode_physical(Label::Tat, 15.0, 0, Ssm::NormalOperation).unwrap();
+    /// assert_eq!(decode(word).unwrap().to_physical_in(Unit::Fahrenheit), Some(59.0));
+    /// ```
+    pub fn to_physical_in(&self, unit: Unit) -> Option<f64> {
+        units::convert(self.label, self.to_physical()?, unit)
+    }
+
+    /// Scale the data field like [`to_physical`](Self::to_physical), whatever the SSM.
+    ///
+    /// Useful for logging Functional Test or No Computed Data words, whose data field
+    /// may still hold a meaningful number. The caller is responsible for checking
+    /// [`ssm`](Self::ssm): a value returned here is not necessarily valid data.
+    ///
+    /// Returns `None` only if the label is not supported or is BCD.
+    pub fn to_physical_raw(&self) -> Option<f64> {
+        self.label.spec()?.value(self)
+    }
+
+    /// Like [`to_physical`](Self::to_physical), treating the data field as Gray code
+    /// whatever [`Label::is_gray_coded`] says.
+    ///
+    /// Labels flagged as Gray-coded are already converted by `to_physical`; this is for
+    /// equipment that Gray-codes a label the built-in definitions treat as binary.
+    ///
+    /// ```rust
+    /// use arinc429::{encode, decode, Label};
+    ///
+    /// // 250 kt is 2000 counts; its Gray code is 2000 ^ (2000 >> 1).
+    /// let word = decode(encode(0o012, 0, 2000 ^ 1000, 3).unwrap()).unwrap();
+    /// assert_eq!(word.to_physical_gray(), Some(250.0));
+    /// ```
+    pub fn to_physical_gray(&self) -> Option<f64> {
+        let spec = self.label.spec()?;
+        if !spec.ssm_valid(self.ssm) {
+            return None;
+        }
+        spec.with_gray().value(self)
+    }
+
+    /// Like [`to_physical`](Self::to_physical), but interprets the label using `table`.
+    ///
+    /// This allows [`Label::Unknown`] codes defined in a [`LabelTable`] to be scaled. For
+    /// specs with [`LabelSpec::sign_in_ssm`] the SSM supplies the sign, and only the
+    /// Plus/Minus states count as valid.
+    ///
+    /// Decode the word with [`LabelTable::decode`] so that labels the table defines with
+    /// [`LabelSpec::sdi_is_data`] carry their full 21-bit data field.
+    #[cfg(feature = "alloc")]
+    pub fn to_physical_with(&self, table: &LabelTable) -> Option<f64> {
+        table.get(self.label.raw())?.physical(self)
+    }
+
+    /// Like [`to_physical_with`](Self::to_physical_with), preferring `equip`'s own
+    /// definition of the label (see [`LabelTable::get_for`]).
+    #[cfg(feature = "alloc")]
+    pub fn to_physical_for(&self, equip: EquipmentId, table: &LabelTable) -> Option<f64> {
+        table.get_for(equip, self.label.raw())?.physical(self)
+    }
+
+    /// The SSM bits (0–3) as received, for label-specific interpretation.
+    ///
+    /// [`Ssm`] has one variant per bit pattern, so nothing is lost by storing the BNR
+    /// interpretation: `ssm_raw` always equals the bits [`decode`] extracted, and
+    /// [`to_word`](Self::to_word) writes them back unchanged.
+    pub const fn ssm_raw(&self) -> u8 {
+        self.ssm.as_u8()
+    }
+
+    /// Interpret the SSM bits using the BCD convention.
+    ///
+    /// Returns `None` for labels that are not BCD.
+    pub fn bcd_ssm(&self) -> Option<BcdSsm> {
+        if self.label.data_type() != DataType::Bcd {
             return None;
         }
+        Some(BcdSsm::from_u8(self.ssm_raw()))
+    }
 
-        let raw = self.data as i32;
-        let signed = if (raw & 0x40000) != 0 {
-            raw.wrapping_sub(0x80000)
+    /// Pick whichever of two redundant words has the more trustworthy [`Ssm`].
+    ///
+    /// Uses the validity ordering on [`Ssm`]; on a tie `a` is returned.
+    pub fn prefer<'a>(a: &'a Self, b: &'a Self) -> &'a Self {
+        if b.ssm > a.ssm {
+            b
         } else {
-            raw
-        };
+            a
+        }
+    }
+
+    /// The SDI as a typed [`Sdi`]; see its docs for the meaning of [`Sdi::All`].
+    pub fn sdi_enum(&self) -> Sdi {
+        Sdi::from_u8(self.sdi & 0b11).expect("masked to two bits")
+    }
 
-        match self.label {
-            Label::GroundSpeed => Some(self.data as f64 * 0.125),
-            Label::PressureAltitude | Label::BaroCorrectedAlt => Some(signed as f64),
-            Label::Mach => Some(self.data as f64 * 0.001),
-            Label::Tat => Some(signed as f64 * 0.25),
-            Label::TrueAirspeed => Some(self.data as f64),
-            Label::RollAngle => Some(signed as f64 * 0.01),
-            _ => None,
+    /// Sign of a BCD value: `+1` for [`BcdSsm::Plus`], `-1` for [`BcdSsm::Minus`].
+    ///
+    /// Returns `None` for non-BCD labels and for NCD/Functional Test words.
+    pub fn bcd_sign(&self) -> Option<i8> {
+        match self.bcd_ssm()? {
+            BcdSsm::Plus => Some(1),
+            BcdSsm::Minus => Some(-1),
+            BcdSsm::NoComputedData | BcdSsm::FunctionalTest => None,
         }
     }
 
-    /// Decode BCD Date (label 260) → `"dd-mm-yy"` string.
+    /// Read a single data bit using ARINC 1-based bit numbering.
+    ///
+    /// The data field occupies ARINC bits 11–29, so ARINC bit `n` is bit `n - 11` of
+    /// [`ArincWord::data`]. Returns `None` for bits outside the data field.
+    pub fn bit(&self, arinc_bit: u8) -> Option<bool> {
+        if !(11..=29).contains(&arinc_bit) {
+            return None;
+        }
+        Some((self.bits_11_to_29() >> (arinc_bit - 11)) & 1 == 1)
+    }
+
+    /// Extract a sub-field spanning ARINC bits `range` (e.g. `13..=15`), LSB-aligned.
+    ///
+    /// # Panics
+    /// If the range is empty or extends outside the data field (bits 11–29).
+    pub fn bits(&self, range: RangeInclusive<u8>) -> u32 {
+        let (lsb, msb) = (*range.start(), *range.end());
+        assert!(
+            (11..=29).contains(&lsb) && (lsb..=29).contains(&msb),
+            "ARINC bit range {}..={} is outside the data field (11..=29)",
+            lsb,
+            msb
+        );
+        (self.bits_11_to_29() >> (lsb - 11)) & field_mask(msb - lsb + 1)
+    }
+
+    /// ARINC bits 11–29, without the SDI bits of a 21-bit data field.
+    fn bits_11_to_29(&self) -> u32 {
+        if self.label.sdi_is_data() {
+            self.data >> 2
+        } else {
+            self.data
+        }
+    }
+
+    /// Re-encode this word into its 32-bit form, recomputing odd parity.
+    ///
+    /// For any valid word `w`, `decode(w)?.to_word()? == w`.
+    ///
+    /// For labels that use the SDI bits as data, the two low bits of the 21-bit data field
+    /// go back into bits 9–10 and [`sdi`](Self::sdi) is ignored.
+    pub fn to_word(&self) -> Result<u32, ArincError> {
+        if self.label.sdi_is_data() {
+            if self.data > 0x1F_FFFF {
+                return Err(ArincError::DataOverflow(self.data));
+            }
+            return encode(
+                self.label.raw(),
+                (self.data & 0x3) as u8,
+                self.data >> 2,
+                self.ssm_raw(),
+            );
+        }
+        encode(self.label.raw(), self.sdi, self.data, self.ssm_raw())
+    }
+
+    /// Equipment ID carried by an Equipment Identification word (label 377).
+    ///
+    /// The ID is three hexadecimal digits in ARINC bits 11–22, e.g. `0x006` for a DADC
+    /// (see [`EquipmentId`]). Bits 23–29 are ignored. The SSM is not checked, since
+    /// label 377 uses the discrete-word convention where `00` is valid data.
+    ///
+    /// Returns `None` for any other label.
+    pub fn to_equipment_id(&self) -> Option<u16> {
+        if self.label != Label::EquipmentIdentification {
+            return None;
+        }
+        Some((self.data & 0xFFF) as u16)
+    }
+
+    /// Decode BCD Date (label 260) into its numeric day/month/year fields.
     ///
     /// Returns `None` if label mismatch, invalid BCD digits, or SSM not Normal.
-    pub fn to_bcd_date(&self) -> Option<String> {
+    pub fn to_date(&self) -> Option<BcdDate> {
         if self.label != Label::Date || !matches!(self.ssm, Ssm::NormalOperation) {
             return None;
         }
 
-        let d = self.data;
-        let year_units = (d & 0xF) as u8;
-        let year_tens = ((d >> 4) & 0xF) as u8;
-        let month_units = ((d >> 8) & 0xF) as u8;
-        let month_tens = ((d >> 12) & 0x1) as u8;
-        let day_units = ((d >> 13) & 0xF) as u8;
-        let day_tens = ((d >> 17) & 0x3) as u8;
-
-        if year_tens > 9
-            || year_units > 9
-            || month_tens > 1
-            || month_units > 9
-            || day_tens > 3
-            || day_units > 9
-            || (month_tens * 10 + month_units) == 0
-            || (day_tens * 10 + day_units) == 0
-        {
+        let digits = bcd::digits(self.data, &bcd::DATE_LAYOUT)?;
+        let day = digits[0] * 10 + digits[1];
+        let month = digits[2] * 10 + digits[3];
+        let year = digits[4] * 10 + digits[5];
+
+        if month == 0 || day == 0 {
             return None;
         }
 
-        Some(format!(
-            "{:02}-{:02}-{:02}",
-            day_tens * 10 + day_units,
-            month_tens * 10 + month_units,
-            year_tens * 10 + year_units
-        ))
+        Some(BcdDate { day, month, year })
     }
 
-    /// Decode BCD UTC Time (label 150) → `"hh:mm:ss"` string.
+    /// Decode BCD UTC Time (label 150) into its numeric hour/minute/second fields.
     ///
     /// Returns `None` if label mismatch, invalid BCD digits, or SSM not Normal.
-    pub fn to_bcd_time(&self) -> Option<String> {
+    pub fn to_time(&self) -> Option<BcdTime> {
         if self.label != Label::UtcTime || !matches!(self.ssm, Ssm::NormalOperation) {
             return None;
         }
 
-        let d = self.data;
-        let sec_units = (d & 0xF) as u8;
-        let sec_tens = ((d >> 4) & 0x7) as u8;
-        let min_units = ((d >> 7) & 0xF) as u8;
-        let min_tens = ((d >> 11) & 0x7) as u8;
-        let hour_units = ((d >> 14) & 0xF) as u8;
-        let hour_tens = ((d >> 18) & 0x3) as u8;
-
-        if hour_tens > 2
-            || hour_units > 9
-            || min_tens > 5
-            || min_units > 9
-            || sec_tens > 5
-            || sec_units > 9
-        {
-            return None;
-        }
+        let digits = bcd::digits(self.data, &bcd::TIME_LAYOUT)?;
 
-        Some(format!(
-            "{:02}:{:02}:{:02}",
-            hour_tens * 10 + hour_units,
-            min_tens * 10 + min_units,
-            sec_tens * 10 + sec_units
-        ))
+        Some(BcdTime {
+            hour: digits[0] * 10 + digits[1],
+            minute: digits[2] * 10 + digits[3],
+            second: digits[4] * 10 + digits[5],
+        })
     }
-}
 
-/// Encode an ARINC 429 word.
+    /// Decode BCD Date (label 260) → `"dd-mm-yy"` string.
+    ///
+    /// Returns `None` if label mismatch, invalid BCD digits, or SSM not Normal.
+    #[cfg(feature = "alloc")]
+    pub fn to_bcd_date(&self) -> Option<String> {
+        self.to_date().map(|date| date.to_string())
+    }
+
+    /// Decode BCD UTC Time (label 150) → `"hh:mm:ss"` string.
+    ///
+    /// Returns `None` if label mismatch, invalid BCD digits, or SSM not Normal.
+    #[cfg(feature = "alloc")]
+    pub fn to_bcd_time(&self) -> Option<String> {
+        self.to_time().map(|time| time.to_string())
+    }
+}
+
+/// Decodes with odd parity checked, as [`decode`] does.
+///
+/// ```rust
+/// use arinc429::{ArincError, ArincWord, Label};
+///
+/// fn ground_speed(raw: u32) -> Result<Option<f64>, ArincError> {
+///     let word: ArincWord = raw.try_into()?;
+///     Ok(word.to_physical().filter(|_| word.label == Label::GroundSpeed))
+/// }
+/// assert_eq!(ground_speed(0xE01F4050), Ok(Some(250.0)));
+/// assert_eq!(ground_speed(0x601F4050), Err(ArincError::ParityMismatch));
+/// ```
+impl TryFrom<u32> for ArincWord {
+    type Error = ArincError;
+
+    fn try_from(word: u32) -> Result<Self, ArincError> {
+        decode(word)
+    }
+}
+
+/// Encodes with odd parity, as [`ArincWord::to_word`] does.
+///
+/// ```rust
+/// use arinc429::{ArincError, ArincWord, Label, Ssm};
+///
+/// let word = ArincWord { label: Label::GroundSpeed, sdi: 0, data: 2000, ssm: Ssm::NormalOperation };
+/// assert_eq!(u32::try_from(&word), Ok(0xE01F4050));
+///
+/// let too_big = ArincWord { data: 1 << 19, ..word };
+/// assert_eq!(u32::try_from(&too_big), Err(ArincError::DataOverflow(1 << 19)));
+/// ```
+impl TryFrom<&ArincWord> for u32 {
+    type Error = ArincError;
+
+    fn try_from(word: &ArincWord) -> Result<Self, ArincError> {
+        word.to_word()
+    }
+}
+
+/// Canonical human-readable form, e.g. `012 Ground Speed = 250.0 knots [Normal Operation]`.
+///
+/// BCD labels print their decoded date/time; anything without a physical interpretation
+/// falls back to the raw data field.
+impl fmt::Display for ArincWord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.label.write_octal(f)?;
+        write!(f, " {} ", self.label.name())?;
+
+        if let Some(value) = self.to_physical() {
+            write!(f, "= {:?}", value)?;
+            let units = self.label.units();
+            if !units.is_empty() {
+                write!(f, " {}", units)?;
+            }
+        } else if let Some(date) = self.to_date() {
+            write!(f, "= {}", date)?;
+        } else if let Some(time) = self.to_time() {
+            write!(f, "= {}", time)?;
+        } else {
+            write!(f, "data={}", self.data)?;
+        }
+
+        write!(f, " [{}]", self.ssm.name())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ArincWord {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("ArincWord", 5)?;
+        state.serialize_field("label", &self.label)?;
+        let mut octal = [0u8; 3];
+        state.serialize_field("octal", self.label.octal_to(&mut octal))?;
+        state.serialize_field("sdi", &self.sdi)?;
+        state.serialize_field("data", &self.data)?;
+        state.serialize_field("ssm", &self.ssm)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ArincWord {
+    /// Decode BCD UTC Time (label 150) into a [`chrono::NaiveTime`].
+    ///
+    /// Returns `None` under the same conditions as [`ArincWord::to_time`], or if the
+    /// hour is outside 0–23.
+    pub fn to_naive_time(&self) -> Option<chrono::NaiveTime> {
+        let time = self.to_time()?;
+        chrono::NaiveTime::from_hms_opt(time.hour.into(), time.minute.into(), time.second.into())
+    }
+
+    /// Decode BCD Date (label 260) into a [`chrono::NaiveDate`].
+    ///
+    /// ARINC 429 only carries a two-digit year, so `century` (e.g. `2000`) is added to it.
+    /// Returns `None` under the same conditions as [`ArincWord::to_date`], or for
+    /// calendar-impossible dates such as 31-02.
+    pub fn to_naive_date(&self, century: i32) -> Option<chrono::NaiveDate> {
+        let date = self.to_date()?;
+        chrono::NaiveDate::from_ymd_opt(
+            century + i32::from(date.year),
+            date.month.into(),
+            date.day.into(),
+        )
+    }
+}
+
+/// Date decoded from a BCD Date word (label 260).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BcdDate {
+    /// Day of month (1–39 as permitted by the BCD field)
+    pub day: u8,
+    /// Month (1–19 as permitted by the BCD field)
+    pub month: u8,
+    /// Two-digit year (00–99)
+    pub year: u8,
+}
+
+impl fmt::Display for BcdDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}-{:02}-{:02}", self.day, self.month, self.year)
+    }
+}
+
+/// Time of day decoded from a BCD UTC Time word (label 150).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BcdTime {
+    /// Hours (0–29 as permitted by the BCD field)
+    pub hour: u8,
+    /// Minutes (0–59)
+    pub minute: u8,
+    /// Seconds (0–59)
+    pub second: u8,
+}
+
+impl fmt::Display for BcdTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
+/// Parity scheme carried in bit 32 of the word.
+///
+/// ARINC 429 mandates [`Parity::Odd`]; [`Parity::Even`] and [`Parity::None`] exist for
+/// non-standard implementations and lab test rigs. Using the same scheme for encoding
+/// and decoding is the caller's responsibility.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Parity {
+    /// Odd parity (ARINC 429 standard)
+    #[default]
+    Odd,
+    /// Even parity (non-standard)
+    Even,
+    /// No parity (non-standard): bit 32 is the most significant bit of a 20-bit data
+    /// field, above ARINC bits 11–29, and is never checked
+    None,
+}
+
+impl Parity {
+    /// Check whether a full 32-bit word satisfies this parity scheme.
+    const fn is_valid(&self, word: u32) -> bool {
+        let odd = word.count_ones() % 2 == 1;
+        match self {
+            Parity::Odd => odd,
+            Parity::Even => !odd,
+            Parity::None => true,
+        }
+    }
+
+    /// Widest data value the scheme can carry.
+    const fn max_data(&self) -> u32 {
+        match self {
+            Parity::None => 0xF_FFFF,
+            Parity::Odd | Parity::Even => 0x7FFFF,
+        }
+    }
+
+    /// Set bit 32 of a word whose bit 32 is clear so that it satisfies this scheme.
+    ///
+    /// Under [`Parity::None`] bit 32 is data, so the word is returned unchanged.
+    const fn apply(&self, word: u32) -> u32 {
+        if self.is_valid(word) {
+            word
+        } else {
+            word | (1 << 31)
+        }
+    }
+}
+
+/// Check that a raw word has valid (odd) ARINC 429 parity.
+pub const fn check_parity(word: u32) -> bool {
+    Parity::Odd.is_valid(word)
+}
+
+/// Set bit 32 so that `word` has odd parity over all 32 bits.
+///
+/// Useful for making a hand-assembled word (see [`set_field`]) decodable; bits 1–31
+/// are left untouched.
+///
+/// ```rust
+/// use arinc429::{decode, fix_parity};
+///
+/// assert!(decode(fix_parity(0x0000_0050)).is_ok());
+/// ```
+pub const fn fix_parity(word: u32) -> u32 {
+    Parity::Odd.apply(word & 0x7FFF_FFFF)
+}
+
+/// Encode an ARINC 429 word.
 ///
 /// Performs label bit reversal, packs fields, and adds odd parity.
 ///
@@ -338,89 +1861,2017 @@ impl ArincWord {
 /// # Returns
 /// 32-bit ARINC 429 word on success
 pub fn encode(label: u8, sdi: u8, data: u32, ssm: u8) -> Result<u32, ArincError> {
+    encode_with_parity(label, sdi, data, ssm, Parity::Odd)
+}
+
+/// Encode an ARINC 429 word without validating the fields, usable in `const` contexts.
+///
+/// The caller guarantees the ranges that [`encode`] checks; out-of-range values are
+/// masked to their field width (SDI and SSM to 2 bits, data to 19 bits) rather than
+/// reported.
+///
+/// ```rust
+/// use arinc429::encode_raw;
+///
+/// const GROUND_SPEED_250: u32 = encode_raw(0o012, 0, 2000, 3);
+/// assert_eq!(GROUND_SPEED_250, 0xE01F4050);
+/// ```
+pub const fn encode_raw(label: u8, sdi: u8, data: u32, ssm: u8) -> u32 {
+    Parity::Odd.apply(pack(label, sdi & 0x3, data & 0x7FFFF, ssm & 0x3))
+}
+
+/// Bit-reversed value of every byte, for the label field (bits 1–8 are sent MSB first).
+pub(crate) const REVERSE: [u8; 256] = {
+    let mut table = [0u8; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut bit = 0;
+        while bit < 8 {
+            if i & (1 << bit) != 0 {
+                table[i] |= 0x80 >> bit;
+            }
+            bit += 1;
+        }
+        i += 1;
+    }
+    table
+};
+
+/// Place in-range fields at their bit positions, reversing the label. Bit 32 is left clear.
+const fn pack(label: u8, sdi: u8, data: u32, ssm: u8) -> u32 {
+    let label_bits = REVERSE[label as usize];
+    (label_bits as u32) | ((sdi as u32) << 8) | (data << 10) | ((ssm as u32) << 29)
+}
+
+/// Encode an ARINC 429 word with a typed [`Sdi`].
+///
+/// Same as [`encode`], but the SDI cannot be out of range.
+pub fn encode_with_sdi(label: u8, sdi: Sdi, data: u32, ssm: u8) -> Result<u32, ArincError> {
+    encode(label, sdi.as_u8(), data, ssm)
+}
+
+/// Encode an ARINC 429 word using the given [`Parity`] scheme.
+///
+/// Identical to [`encode`] except for how bit 32 is computed. With [`Parity::None`],
+/// `data` may be up to 20 bits wide and its top bit is sent in bit 32.
+pub fn encode_with_parity(
+    label: u8,
+    sdi: u8,
+    data: u32,
+    ssm: u8,
+    parity: Parity,
+) -> Result<u32, ArincError> {
     if sdi > 3 {
         return Err(ArincError::InvalidSdi(sdi));
     }
     if ssm > 3 {
         return Err(ArincError::InvalidSsm(ssm));
     }
-    if data > 0x7FFFF {
+    if data > parity.max_data() {
         return Err(ArincError::DataOverflow(data));
     }
 
-    let label_bits = label.reverse_bits();
-    let mut word = (label_bits as u32)
-        | ((sdi as u32) << 8)
-        | (data << 10)
-        | ((ssm as u32) << 29);
+    let word = pack(label, sdi, data & 0x7FFFF, ssm) | (data >> 19) << 31;
+    Ok(parity.apply(word))
+}
+
+/// Encode an ARINC 429 word and serialize it to bytes in the given byte order.
+///
+/// The byte-level counterpart of [`decode_slice`] (little-endian) and
+/// [`WordReader`](crate::WordReader).
+pub fn encode_to_bytes(
+    label: u8,
+    sdi: u8,
+    data: u32,
+    ssm: u8,
+    endian: Endian,
+) -> Result<[u8; 4], ArincError> {
+    encode(label, sdi, data, ssm).map(|word| endian.word_to_bytes(word))
+}
+
+/// Encode a physical value (knots, feet, °C, ...) directly into an ARINC 429 word.
+///
+/// This is the inverse of [`ArincWord::to_physical`]: the value is divided by the label's
+/// resolution, rounded to the nearest raw count and, for signed labels, stored as 19-bit
+/// two's complement.
+///
+/// For labels that use the SDI bits as data (see [`Label::sdi_is_data`]) the value fills
+/// bits 9–29 and `sdi` is ignored.
+///
+/// # Errors
+/// - [`ArincError::UnsupportedLabel`] for BCD or unknown labels
+/// - [`ArincError::ValueOutOfRange`] if the value does not fit in the data field
+/// - [`ArincError::InvalidSdi`] if `sdi` is greater than 3
+pub fn encode_physical(label: Label, value: f64, sdi: u8, ssm: Ssm) -> Result<u32, ArincError> {
+    encode_physical_checked(label, value, sdi, ssm).map(|(word, _)| word)
+}
+
+/// Like [`encode_physical`], also returning the quantization error: `value` minus the
+/// value actually encoded.
+///
+/// The error is at most half the label's resolution in magnitude, and lets calibration
+/// code judge whether that resolution is adequate.
+///
+/// ```rust
+/// use arinc429::{encode_physical_checked, Label, Ssm};
+///
+/// let (_, error) = encode_physical_checked(Label::Mach, 0.8, 0, Ssm::NormalOperation).unwrap();
+/// assert!(error.abs() < 1e-12);
+/// ```
+///
+/// # Errors
+/// As for [`encode_physical`].
+pub fn encode_physical_checked(
+    label: Label,
+    value: f64,
+    sdi: u8,
+    ssm: Ssm,
+) -> Result<(u32, f64), ArincError> {
+    let resolution = label
+        .resolution()
+        .ok_or(ArincError::UnsupportedLabel(label))?;
+    let (min, max) = label.range().ok_or(ArincError::UnsupportedLabel(label))?;
+    if !(min..=max).contains(&value) {
+        return Err(ArincError::ValueOutOfRange { label, value });
+    }
+
+    let bits = label
+        .significant_bits()
+        .ok_or(ArincError::UnsupportedLabel(label))?;
+
+    let counts = round(value / resolution);
+    let data = (counts as i32 as u32) & field_mask(bits);
+    let word = if label.sdi_is_data() {
+        encode(label.raw(), (data & 0x3) as u8, data >> 2, ssm.as_u8())?
+    } else {
+        encode(label.raw(), sdi, data, ssm.as_u8())?
+    };
+    Ok((word, value - counts * resolution))
+}
+
+/// Encode several physical values with [`encode_physical`], all with the same SDI and SSM.
+///
+/// Results are returned in input order, one per entry, so a value that fails to encode
+/// does not prevent the others.
+///
+/// ```rust
+/// use arinc429::{encode_batch, Label, Ssm};
+///
+/// let words = encode_batch(
+///     &[(Label::GroundSpeed, 250.0), (Label::Mach, -1.0)],
+///     0,
+///     Ssm::NormalOperation,
+/// );
+/// assert_eq!(words[0], (Label::GroundSpeed, Ok(0xE01F4050)));
+/// assert!(words[1].1.is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_batch(
+    params: &[(Label, f64)],
+    sdi: u8,
+    ssm: Ssm,
+) -> Vec<(Label, Result<u32, ArincError>)> {
+    params
+        .iter()
+        .map(|&(label, value)| (label, encode_physical(label, value, sdi, ssm)))
+        .collect()
+}
+
+/// Fluent builder for ARINC 429 words.
+///
+/// SDI defaults to 0, SSM to [`Ssm::NormalOperation`] and data to 0. [`data`](Self::data)
+/// and [`physical`](Self::physical) are mutually exclusive; the last one set wins.
+///
+/// ```rust
+/// use arinc429::{ArincWordBuilder, Label, Ssm};
+///
+/// let word = ArincWordBuilder::new(Label::GroundSpeed)
+///     .ssm(Ssm::NormalOperation)
+///     .physical(250.0)
+///     .build()
+///     .unwrap();
+/// assert_eq!(word, 0xE01F4050);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArincWordBuilder {
+    label: Label,
+    sdi: u8,
+    ssm: Ssm,
+    payload: Payload,
+}
+
+/// Data field source for [`ArincWordBuilder`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Payload {
+    Data(u32),
+    Physical(f64),
+}
+
+impl ArincWordBuilder {
+    /// Start building a word for the given label.
+    pub fn new(label: Label) -> Self {
+        Self {
+            label,
+            sdi: 0,
+            ssm: Ssm::NormalOperation,
+            payload: Payload::Data(0),
+        }
+    }
+
+    /// Set the Source/Destination Identifier (0–3).
+    pub fn sdi(mut self, sdi: u8) -> Self {
+        self.sdi = sdi;
+        self
+    }
+
+    /// Set the Sign/Status Matrix.
+    pub fn ssm(mut self, ssm: Ssm) -> Self {
+        self.ssm = ssm;
+        self
+    }
+
+    /// Set the raw 19-bit data field.
+    pub fn data(mut self, data: u32) -> Self {
+        self.payload = Payload::Data(data);
+        self
+    }
+
+    /// Set the data field from a physical value, scaled as in [`encode_physical`].
+    pub fn physical(mut self, value: f64) -> Self {
+        self.payload = Payload::Physical(value);
+        self
+    }
+
+    /// Encode the word.
+    pub fn build(&self) -> Result<u32, ArincError> {
+        match self.payload {
+            Payload::Data(data) => encode(self.label.raw(), self.sdi, data, self.ssm.as_u8()),
+            Payload::Physical(value) => encode_physical(self.label, value, self.sdi, self.ssm),
+        }
+    }
+}
+
+/// Shift and mask for ARINC bits `arinc_msb` down to `arinc_lsb` (1-based, bit 1 = LSB).
+fn arinc_span(arinc_msb: u8, arinc_lsb: u8) -> (u32, u32) {
+    assert!(
+        (1..=32).contains(&arinc_lsb) && (arinc_lsb..=32).contains(&arinc_msb),
+        "ARINC bit range {}..={} is outside the word (1..=32)",
+        arinc_lsb,
+        arinc_msb
+    );
+    let width = u32::from(arinc_msb - arinc_lsb) + 1;
+    (u32::from(arinc_lsb - 1), u32::MAX >> (32 - width))
+}
+
+/// Extract ARINC bits `arinc_msb` down to `arinc_lsb` of a raw word, LSB-aligned.
+///
+/// ARINC documents number bits 1–32 starting from the least significant bit, so the
+/// label is bits 1–8 and the SSM bits 30–31. No label reversal is applied.
+///
+/// ```rust
+/// use arinc429::get_field;
+///
+/// assert_eq!(get_field(0xE01F4050, 31, 30), 0b11); // SSM
+/// ```
+///
+/// # Panics
+/// If `arinc_lsb` is 0 or greater than `arinc_msb`, or `arinc_msb` is greater than 32.
+pub fn get_field(word: u32, arinc_msb: u8, arinc_lsb: u8) -> u32 {
+    let (shift, mask) = arinc_span(arinc_msb, arinc_lsb);
+    (word >> shift) & mask
+}
+
+/// Replace ARINC bits `arinc_msb` down to `arinc_lsb` of a raw word with `value`.
+///
+/// High bits of `value` that do not fit in the range are discarded. Parity is not
+/// recomputed.
+///
+/// # Panics
+/// Under the same conditions as [`get_field`].
+pub fn set_field(word: u32, arinc_msb: u8, arinc_lsb: u8, value: u32) -> u32 {
+    let (shift, mask) = arinc_span(arinc_msb, arinc_lsb);
+    (word & !(mask << shift)) | ((value & mask) << shift)
+}
+
+/// Round half away from zero, like `f64::round` (which is not available in `core`).
+fn round(value: f64) -> f64 {
+    let truncated = value as i64 as f64;
+    let fraction = value - truncated;
+    if fraction >= 0.5 {
+        truncated + 1.0
+    } else if fraction <= -0.5 {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+/// Mask covering the low `bits` bits of a data field.
+fn field_mask(bits: u8) -> u32 {
+    (1u32 << bits) - 1
+}
+
+/// Convert a reflected binary (Gray) code to plain binary.
+///
+/// ```rust
+/// use arinc429::gray_to_binary;
+///
+/// assert_eq!(gray_to_binary(0b1101), 0b1001);
+/// assert_eq!(gray_to_binary(10 ^ (10 >> 1)), 10);
+/// ```
+pub const fn gray_to_binary(gray: u32) -> u32 {
+    let mut binary = gray;
+    let mut shift = 1;
+    while shift < 32 {
+        binary ^= binary >> shift;
+        shift <<= 1;
+    }
+    binary
+}
+
+/// Encode a signed count as a 19-bit two's complement data field.
+///
+/// ```rust
+/// use arinc429::{from_twos_complement_19, to_twos_complement_19};
+///
+/// assert_eq!(to_twos_complement_19(-1), Ok(0x7FFFF));
+/// assert_eq!(from_twos_complement_19(0x7FFFF), -1);
+/// ```
+///
+/// # Errors
+/// [`ArincError::DataOverflow`] if `value` is outside `-262144..=262143`; the error
+/// carries `value`'s bit pattern.
+pub const fn to_twos_complement_19(value: i32) -> Result<u32, ArincError> {
+    if value < -(1 << 18) || value >= 1 << 18 {
+        return Err(ArincError::DataOverflow(value as u32));
+    }
+    Ok(value as u32 & 0x7FFFF)
+}
+
+/// Interpret the low 19 bits of `data` as a two's complement number, the inverse of
+/// [`to_twos_complement_19`].
+pub const fn from_twos_complement_19(data: u32) -> i32 {
+    ((data << 13) as i32) >> 13
+}
 
-    let ones = (word & 0x7FFFFFFF).count_ones();
-    let parity = if ones % 2 == 0 { 1 << 31 } else { 0 };
-    word |= parity;
+/// Concatenate the data fields of a parameter split across two words.
+///
+/// The low `msw_bits` bits of `msw`'s data field become the high bits of the result, above
+/// all 19 bits of `lsw`'s, giving a `19 + msw_bits`-bit integer. Both words must carry the
+/// same label and SDI; SSM is not checked. For present position, where the two halves use
+/// different labels and scales, see [`combine_position`].
+///
+/// ```rust
+/// use arinc429::{combine_extended, decode, encode};
+///
+/// let msw = decode(encode(0o100, 1, 0b101, 3).unwrap()).unwrap();
+/// let lsw = decode(encode(0o100, 1, 7, 3).unwrap()).unwrap();
+/// assert_eq!(combine_extended(&msw, &lsw, 3), Some(0b101 << 19 | 7));
+/// ```
+///
+/// Returns `None` if the labels or SDIs differ, or `msw_bits` is more than 13.
+pub fn combine_extended(msw: &ArincWord, lsw: &ArincWord, msw_bits: u8) -> Option<u32> {
+    if msw.label != lsw.label || msw.sdi != lsw.sdi || msw_bits > 13 {
+        return None;
+    }
+    Some((msw.data & field_mask(msw_bits)) << 19 | lsw.data)
+}
 
-    Ok(word)
+/// Interpret the low `bits` bits of `field` as a two's complement number.
+fn sign_extend(field: u32, bits: u8) -> i32 {
+    let shift = 32 - u32::from(bits);
+    ((field << shift) as i32) >> shift
 }
 
 /// Decode a 32-bit ARINC 429 word.
 ///
 /// Validates odd parity, reverses label bits, extracts fields, and maps SSM/label.
+/// For labels with [`Label::sdi_is_data`], bits 9–10 are folded into a 21-bit data field
+/// instead of being read as the SDI.
 ///
 /// # Returns
 /// [`ArincWord`] struct on success
-pub fn decode(word: u32) -> Result<ArincWord, ArincError> {
-    if word.count_ones() % 2 == 0 {
+pub const fn decode(word: u32) -> Result<ArincWord, ArincError> {
+    match decode_unchecked(word) {
+        (decoded, true) => Ok(decoded),
+        (_, false) => Err(ArincError::ParityMismatch),
+    }
+}
+
+/// Decode a 32-bit ARINC 429 word, validating it against the given [`Parity`] scheme.
+///
+/// Returns [`ArincError::ParityMismatch`] if the word does not satisfy `parity`. With
+/// [`Parity::None`] bit 32 becomes bit 19 of [`ArincWord::data`] (bit 21 for labels that
+/// use the SDI bits as data).
+pub const fn decode_with_parity(word: u32, parity: Parity) -> Result<ArincWord, ArincError> {
+    if !parity.is_valid(word) {
         return Err(ArincError::ParityMismatch);
     }
 
-    let label_bits = (word & 0xFF) as u8;
-    let label = label_bits.reverse_bits();
-    let sdi = ((word >> 8) & 0x3) as u8;
-    let data = (word >> 10) & 0x7FFFF;
-    let ssm_raw = ((word >> 29) & 0x3) as u8;
+    let mut decoded = unpack(word);
+    if let Parity::None = parity {
+        let width = if decoded.label.sdi_is_data() { 21 } else { 19 };
+        decoded.data |= (word >> 31) << width;
+    }
+    Ok(decoded)
+}
 
-    Ok(ArincWord {
-        label: Label::from_u8(label),
-        sdi,
-        data,
-        ssm: Ssm::from_u8(ssm_raw),
-    })
+/// Decode a 32-bit ARINC 429 word without rejecting parity errors.
+///
+/// The fields are always extracted; the returned flag is `true` if the word has valid
+/// odd parity. Useful for logging corruption on a noisy bus.
+pub const fn decode_unchecked(word: u32) -> (ArincWord, bool) {
+    (unpack(word), Parity::Odd.is_valid(word))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Byte order of 32-bit words in a buffer, file or stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Endian {
+    /// Most significant byte first
+    Big,
+    /// Least significant byte first
+    #[default]
+    Little,
+}
 
-    #[test]
-    fn test_all_labels_parse() {
-        assert_eq!(Label::from_octal_str("012").unwrap(), Label::GroundSpeed);
-        assert_eq!(Label::from_octal_str("150").unwrap(), Label::UtcTime);
-        assert_eq!(Label::from_octal_str("260").unwrap(), Label::Date);
+impl Endian {
+    /// Assemble a word from 4 bytes in this byte order.
+    fn word_from_bytes(&self, bytes: [u8; 4]) -> u32 {
+        match self {
+            Endian::Big => u32::from_be_bytes(bytes),
+            Endian::Little => u32::from_le_bytes(bytes),
+        }
     }
 
-    #[test]
-    fn test_bcd_time() {
-        let data =
-            (0b01 << 18) | (0b0010 << 14) | (0b011 << 11) | (0b0100 << 7) | (0b101 << 4) | 0b0110;
-        let word = encode(104, 0, data, 3).unwrap();
-        let decoded = decode(word).unwrap();
-        assert_eq!(decoded.to_bcd_time(), Some("12:34:56".to_string()));
+    /// Split a word into 4 bytes in this byte order.
+    fn word_to_bytes(&self, word: u32) -> [u8; 4] {
+        match self {
+            Endian::Big => word.to_be_bytes(),
+            Endian::Little => word.to_le_bytes(),
+        }
     }
+}
 
-    #[test]
-    fn test_bcd_date() {
-        let data =
-            (0b00 << 17) | (0b0110 << 13) | (0b0 << 12) | (0b0001 << 8) | (0b0010 << 4) | 0b0110;
-        let word = encode(176, 0, data, 3).unwrap();
-        let decoded = decode(word).unwrap();
-        assert_eq!(decoded.to_bcd_date(), Some("06-01-26".to_string()));
+/// How capture hardware assembled the serial bit stream into a 32-bit word.
+///
+/// ARINC 429 transmits bit 1 (the label's most significant bit after reversal, stored
+/// in the word's LSB) first and bit 32 (parity) last. [`Standard`](Self::Standard)
+/// captures store the first bit received in the LSB, which is the layout every other
+/// function in this crate expects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WireOrder {
+    /// First bit received in the LSB (bit 1)
+    #[default]
+    Standard,
+    /// First bit received in the MSB: the whole word is bit-reversed
+    Reversed,
+}
+
+impl WireOrder {
+    /// Convert a captured word to the standard layout.
+    pub const fn to_standard(&self, word: u32) -> u32 {
+        match self {
+            WireOrder::Standard => word,
+            WireOrder::Reversed => word.reverse_bits(),
+        }
     }
+}
 
-    #[test]
-    fn test_cross_py_ground_speed() {
-        let word: u32 = 0xE01F4050;
-        let decoded = decode(word).unwrap();
-        assert_eq!(decoded.label, Label::GroundSpeed);
-        assert_eq!(decoded.ssm, Ssm::NormalOperation);
-        assert_eq!(decoded.to_physical(), Some(250.0));
+/// Decode a word captured in the given bit order.
+///
+/// ```rust
+/// use arinc429::{decode_with_order, Label, WireOrder};
+///
+/// let captured = 0xE01F4050u32.reverse_bits();
+/// let word = decode_with_order(captured, WireOrder::Reversed).unwrap();
+/// assert_eq!(word.label, Label::GroundSpeed);
+/// ```
+pub const fn decode_with_order(word: u32, order: WireOrder) -> Result<ArincWord, ArincError> {
+    decode(order.to_standard(word))
+}
+
+/// Decode a word, calling `hook` with the raw label code if the label is
+/// [`Label::Unknown`].
+///
+/// The hook only fires for words that pass parity. Useful for collecting the labels on a
+/// bus that this crate does not recognise:
+///
+/// ```rust
+/// use std::collections::BTreeSet;
+/// use arinc429::{decode_with_hook, encode};
+///
+/// let mut unrecognised = BTreeSet::new();
+/// for word in [encode(0o012, 0, 0, 3).unwrap(), encode(0o100, 0, 0, 3).unwrap()] {
+///     decode_with_hook(word, |code| {
+///         unrecognised.insert(code);
+///     })
+///     .unwrap();
+/// }
+/// assert_eq!(unrecognised, BTreeSet::from([0o100]));
+/// ```
+pub fn decode_with_hook(word: u32, mut hook: impl FnMut(u8)) -> Result<ArincWord, ArincError> {
+    let decoded = decode(word)?;
+    if let Label::Unknown(code) = decoded.label {
+        hook(code);
+    }
+    Ok(decoded)
+}
+
+/// Decode a buffer of packed little-endian 32-bit words.
+///
+/// Yields one result per 4-byte word. If the buffer length is not a multiple of 4, a final
+/// [`ArincError::TruncatedBuffer`] reports the leftover bytes.
+pub fn decode_slice(bytes: &[u8]) -> impl Iterator<Item = Result<ArincWord, ArincError>> + '_ {
+    let chunks = bytes.chunks_exact(4);
+    let remaining = chunks.remainder().len();
+
+    chunks
+        .map(|chunk| {
+            decode(Endian::Little.word_from_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        })
+        .chain((remaining != 0).then_some(Err(ArincError::TruncatedBuffer { remaining })))
+}
+
+/// Decode every word in `words`, allocating the result vector once.
+///
+/// ```rust
+/// use arinc429::{decode_many, encode, ArincError};
+///
+/// let results = decode_many(&[encode(0o012, 0, 2000, 3).unwrap(), 0x6000_0000]);
+/// assert!(results[0].is_ok());
+/// assert_eq!(results[1], Err(ArincError::ParityMismatch));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_many(words: &[u32]) -> Vec<Result<ArincWord, ArincError>> {
+    let mut out = Vec::with_capacity(words.len());
+    decode_many_into(words, &mut out);
+    out
+}
+
+/// Like [`decode_many`], but reuses `out`, which is cleared first.
+///
+/// Once `out` has grown to the batch size, steady-state loops no longer allocate.
+#[cfg(feature = "alloc")]
+pub fn decode_many_into(words: &[u32], out: &mut Vec<Result<ArincWord, ArincError>>) {
+    out.clear();
+    out.extend(words.iter().map(|&word| decode(word)));
+}
+
+/// Decode `words` into a fixed-capacity vector, for targets without an allocator.
+///
+/// `out` is cleared first. Words failing parity are skipped. If `out` fills up before
+/// the input is exhausted, decoding stops and the error reports how many input words
+/// were consumed, so the rest can be decoded with `&words[err.consumed..]` once `out`
+/// has been drained.
+///
+/// ```rust
+/// use arinc429::{decode_into_heapless, encode};
+///
+/// let words = [encode(0o012, 0, 1, 3).unwrap(), encode(0o012, 0, 2, 3).unwrap()];
+/// let mut out = heapless::Vec::<_, 1>::new();
+/// let err = decode_into_heapless(&words, &mut out).unwrap_err();
+/// assert_eq!((out.len(), err.consumed), (1, 1));
+/// ```
+///
+/// # Errors
+/// [`CapacityError`] if `out` is full before every word has been consumed.
+#[cfg(feature = "heapless")]
+pub fn decode_into_heapless<const N: usize>(
+    words: &[u32],
+    out: &mut heapless::Vec<ArincWord, N>,
+) -> Result<(), CapacityError> {
+    out.clear();
+    for (consumed, &word) in words.iter().enumerate() {
+        if let Ok(decoded) = decode(word) {
+            if out.push(decoded).is_err() {
+                return Err(CapacityError { consumed });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Output buffer filled before the input was exhausted; see [`decode_into_heapless`].
+#[cfg(feature = "heapless")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError {
+    /// Number of input words processed (decoded or skipped) before the buffer filled
+    pub consumed: usize,
+}
+
+#[cfg(feature = "heapless")]
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Output full after {} input words", self.consumed)
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl core::error::Error for CapacityError {}
+
+/// Snapshot of the most recent word for each label code in `words`, as a bus monitor
+/// would display it.
+///
+/// Words failing parity are skipped. Later words replace earlier ones with the same
+/// label, regardless of SDI; use [`latest_by_key`] to keep sources apart.
+///
+/// ```rust
+/// use arinc429::{encode, latest_by_label};
+///
+/// let words = [encode(0o012, 0, 1000, 3).unwrap(), encode(0o012, 0, 2000, 3).unwrap()];
+/// assert_eq!(latest_by_label(&words)[&0o012].data, 2000);
+/// ```
+#[cfg(feature = "std")]
+pub fn latest_by_label(words: &[u32]) -> std::collections::HashMap<u8, ArincWord> {
+    latest_by_key(words, |word| word.label.raw())
+}
+
+/// Like [`latest_by_label`], grouping by `key` instead; e.g. `|w| (w.label.raw(), w.sdi)`
+/// keeps the latest word per label from each source.
+#[cfg(feature = "std")]
+pub fn latest_by_key<K: core::hash::Hash + Eq>(
+    words: &[u32],
+    key: impl Fn(&ArincWord) -> K,
+) -> std::collections::HashMap<K, ArincWord> {
+    let mut latest = std::collections::HashMap::new();
+    for word in words.iter().filter_map(|&word| decode(word).ok()) {
+        latest.insert(key(&word), word);
+    }
+    latest
+}
+
+/// Raw label code of a word (bit reversal undone), without checking parity.
+///
+/// For routing words at line rate where [`decode`] would do unnecessary work.
+///
+/// ```rust
+/// use arinc429::{peek_label, peek_sdi};
+///
+/// assert_eq!((peek_label(0xE01F4050), peek_sdi(0xE01F4050)), (0o012, 0));
+/// ```
+#[inline]
+pub const fn peek_label(word: u32) -> u8 {
+    REVERSE[(word & 0xFF) as usize]
+}
+
+/// SDI (0–3) of a word, without checking parity. See [`peek_label`].
+///
+/// This is always ARINC bits 9–10, even for labels that use them as data (see
+/// [`Label::sdi_is_data`]), for which [`decode`] reports an SDI of 0.
+#[inline]
+pub const fn peek_sdi(word: u32) -> u8 {
+    ((word >> 8) & 0x3) as u8
+}
+
+/// Extract the label, SDI, data and SSM fields from a word, folding the SDI bits into
+/// the data field for labels that use them as data.
+const fn unpack(word: u32) -> ArincWord {
+    unpack_as(word, SDI_AS_DATA[peek_label(word) as usize])
+}
+
+/// Extract the fields of a word; with `sdi_is_data`, bits 9–10 become the two low bits
+/// of a 21-bit data field and the SDI is reported as 0.
+pub(crate) const fn unpack_as(word: u32, sdi_is_data: bool) -> ArincWord {
+    let label = peek_label(word);
+    let ssm_raw = ((word >> 29) & 0x3) as u8;
+    let (sdi, data) = if sdi_is_data {
+        (0, (word >> 8) & 0x1F_FFFF)
+    } else {
+        (peek_sdi(word), (word >> 10) & 0x7FFFF)
+    };
+
+    ArincWord {
+        label: Label::from_u8(label),
+        sdi,
+        data,
+        ssm: Ssm::from_u8(ssm_raw),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs under both the `thiserror` derive and the `minimal-error` impl, so the two
+    /// must agree on every message.
+    #[test]
+    fn test_error_messages() {
+        let cases = [
+            (
+                ArincError::DataOverflow(524288),
+                "Data exceeds 19 bits: 524288",
+            ),
+            (ArincError::InvalidSdi(4), "SDI must be 0-3: 4"),
+            (ArincError::InvalidSsm(7), "SSM must be 0-3: 7"),
+            (ArincError::ParityMismatch, "Parity check failed"),
+            (
+                ArincError::InvalidOctalLabel,
+                "Invalid octal label string (expected 1-3 octal digits, 0 to 377)",
+            ),
+            (
+                ArincError::ValueOutOfRange {
+                    label: Label::Mach,
+                    value: -0.5,
+                },
+                "Value -0.5 out of range for label Mach",
+            ),
+            (
+                ArincError::UnsupportedLabel(Label::Unknown(0o377)),
+                "Label Unknown(255) has no physical encoding",
+            ),
+            (
+                ArincError::TruncatedBuffer { remaining: 3 },
+                "Truncated buffer: 3 trailing bytes",
+            ),
+            (ArincError::InvalidBcd, "Invalid BCD value"),
+            (
+                ArincError::InvalidTransfer("missing end word"),
+                "Invalid file transfer: missing end word",
+            ),
+            (ArincError::InvalidHex, "Invalid hex word"),
+            (
+                ArincError::LabelMismatch {
+                    expected: 0o203,
+                    found: 0o12,
+                },
+                "Expected label 203, found 012",
+            ),
+        ];
+        for (error, message) in cases {
+            assert_eq!(error.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn test_all_labels_parse() {
+        assert_eq!(Label::from_octal_str("012").unwrap(), Label::GroundSpeed);
+        assert_eq!(Label::from_octal_str("150").unwrap(), Label::UtcTime);
+        assert_eq!(Label::from_octal_str("260").unwrap(), Label::Date);
+    }
+
+    #[test]
+    fn test_label_representations() {
+        // ICD digits, label code and wire byte all name the same label.
+        let from_str = Label::from_octal_str("203").unwrap();
+        let from_code = Label::from_octal_code(203).unwrap();
+        let from_literal = Label::from_u8(0o203);
+        let wire = encode(0o203, 0, 0, 0).unwrap() & 0xFF;
+        assert_eq!(wire, 0b1100_0001);
+        let from_wire = decode(encode(0o203, 0, 0, 0).unwrap()).unwrap().label;
+        for label in [from_str, from_code, from_literal, from_wire] {
+            assert_eq!(label, Label::PressureAltitude);
+        }
+
+        // The common mistake: 203 as a label code is octal 313.
+        assert_eq!(Label::from_u8(203).octal_code(), 313);
+        assert_eq!(Label::from_octal_code(0o203), Ok(Label::from_u8(0o131)));
+
+        assert_eq!(Label::from_octal_code(12), Ok(Label::GroundSpeed));
+        assert_eq!(
+            Label::from_octal_code(377),
+            Ok(Label::EquipmentIdentification)
+        );
+        assert_eq!(
+            Label::from_octal_code(400),
+            Err(ArincError::InvalidOctalLabel)
+        );
+        assert_eq!(
+            Label::from_octal_code(18),
+            Err(ArincError::InvalidOctalLabel)
+        );
+        for raw in 0..=255u8 {
+            let label = Label::from_u8(raw);
+            assert_eq!(Label::from_octal_code(label.octal_code()), Ok(label));
+        }
+    }
+
+    #[test]
+    fn test_from_octal_str_bounds() {
+        assert_eq!(
+            Label::from_octal_str("377"),
+            Ok(Label::EquipmentIdentification)
+        );
+        assert_eq!(Label::from_octal_str("0"), Ok(Label::Unknown(0)));
+        assert_eq!(Label::from_octal_str("7"), Ok(Label::Unknown(7)));
+        assert_eq!(Label::from_octal_str("001"), Ok(Label::Unknown(1)));
+        for bad in ["400", "777", "08", "9", "", "0012", "+12", " 12", "12 "] {
+            assert_eq!(
+                Label::from_octal_str(bad),
+                Err(ArincError::InvalidOctalLabel),
+                "{:?}",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_bcd_time() {
+        let data =
+            (0b01 << 18) | (0b0010 << 14) | (0b011 << 11) | (0b0100 << 7) | (0b101 << 4) | 0b0110;
+        let word = encode(104, 0, data, 3).unwrap();
+        let decoded = decode(word).unwrap();
+        assert_eq!(decoded.to_bcd_time(), Some("12:34:56".to_string()));
+        assert_eq!(
+            decoded.to_time(),
+            Some(BcdTime {
+                hour: 12,
+                minute: 34,
+                second: 56
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_bcd_date() {
+        let data =
+            (0b00 << 17) | (0b0110 << 13) | (0b0 << 12) | (0b0001 << 8) | (0b0010 << 4) | 0b0110;
+        let word = encode(176, 0, data, 3).unwrap();
+        let decoded = decode(word).unwrap();
+        assert_eq!(decoded.to_bcd_date(), Some("06-01-26".to_string()));
+        assert_eq!(
+            decoded.to_date(),
+            Some(BcdDate {
+                day: 6,
+                month: 1,
+                year: 26
+            })
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_date_time() {
+        let time =
+            (0b01 << 18) | (0b0010 << 14) | (0b011 << 11) | (0b0100 << 7) | (0b101 << 4) | 0b0110;
+        let decoded = decode(encode(104, 0, time, 3).unwrap()).unwrap();
+        assert_eq!(
+            decoded.to_naive_time(),
+            chrono::NaiveTime::from_hms_opt(12, 34, 56)
+        );
+
+        let date = (0b0110 << 13) | (0b0001 << 8) | (0b0010 << 4) | 0b0110;
+        let decoded = decode(encode(176, 0, date, 3).unwrap()).unwrap();
+        assert_eq!(
+            decoded.to_naive_date(2000),
+            chrono::NaiveDate::from_ymd_opt(2026, 1, 6)
+        );
+
+        // 31-02-26 passes BCD validation but is not a real date
+        let date = (0b11 << 17) | (0b0001 << 13) | (0b0010 << 8) | (0b0010 << 4) | 0b0110;
+        let decoded = decode(encode(176, 0, date, 3).unwrap()).unwrap();
+        assert!(decoded.to_date().is_some());
+        assert_eq!(decoded.to_naive_date(2000), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        let decoded = decode(0xE01F4050).unwrap();
+        let json = serde_json::to_string(&decoded).unwrap();
+        assert!(json.contains(r#""octal":"012""#));
+        assert!(json.contains(r#""data":2000"#));
+        let back: ArincWord = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, decoded);
+
+        let unknown = serde_json::to_string(&Label::Unknown(42)).unwrap();
+        assert_eq!(unknown, r#"{"Unknown":42}"#);
+        assert_eq!(
+            serde_json::from_str::<Label>(&unknown).unwrap(),
+            Label::Unknown(42)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let decoded = decode(0xE01F4050).unwrap();
+        assert_eq!(
+            decoded.to_string(),
+            "012 Ground Speed = 250.0 knots [Normal Operation]"
+        );
+
+        let ncd = decode(encode(10, 0, 2000, 1).unwrap()).unwrap();
+        assert_eq!(
+            ncd.to_string(),
+            "012 Ground Speed data=2000 [No Computed Data]"
+        );
+
+        let date = (0b0110 << 13) | (0b0001 << 8) | (0b0010 << 4) | 0b0110;
+        let decoded = decode(encode(176, 0, date, 3).unwrap()).unwrap();
+        assert_eq!(
+            decoded.to_string(),
+            "260 Date = 06-01-26 [Normal Operation]"
+        );
+    }
+
+    #[test]
+    fn test_to_word_roundtrip() {
+        // xorshift32: deterministic pseudo-random words without extra dependencies
+        let mut state: u32 = 0x1234_5678;
+        for _ in 0..5000 {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+
+            let mut word = state & 0x7FFFFFFF;
+            if word.count_ones().is_multiple_of(2) {
+                word |= 1 << 31;
+            }
+            assert_eq!(decode(word).unwrap().to_word(), Ok(word));
+        }
+    }
+
+    #[test]
+    fn test_even_parity() {
+        let odd = encode(10, 0, 2000, 3).unwrap();
+        let even = encode_with_parity(10, 0, 2000, 3, Parity::Even).unwrap();
+        assert_eq!(odd ^ even, 1 << 31);
+        assert_eq!(even.count_ones() % 2, 0);
+
+        assert_eq!(
+            decode_with_parity(even, Parity::Even).unwrap(),
+            decode(odd).unwrap()
+        );
+        assert_eq!(decode(even), Err(ArincError::ParityMismatch));
+        assert_eq!(
+            decode_with_parity(odd, Parity::Even),
+            Err(ArincError::ParityMismatch)
+        );
+    }
+
+    #[test]
+    fn test_no_parity_carries_bit_32() {
+        let data = 0x80003; // bit 19 lands in ARINC bit 32
+        let word = encode_with_parity(0o100, 0, data, 3, Parity::None).unwrap();
+        assert_eq!(word >> 31, 1);
+        assert_eq!(decode_with_parity(word, Parity::None).unwrap().data, data);
+        assert_eq!(
+            encode_with_parity(0o100, 0, 0x100000, 3, Parity::None),
+            Err(ArincError::DataOverflow(0x100000))
+        );
+
+        // Read as a standard word, bit 32 is parity and this word's is wrong.
+        assert_eq!(decode(word), Err(ArincError::ParityMismatch));
+        assert!(encode(0o100, 0, data, 3).is_err());
+    }
+
+    #[test]
+    fn test_decode_unchecked() {
+        let corrupted = 0xE01F4050 ^ (1 << 31);
+        assert_eq!(decode(corrupted), Err(ArincError::ParityMismatch));
+
+        let (decoded, parity_ok) = decode_unchecked(corrupted);
+        assert!(!parity_ok);
+        assert_eq!(decoded.label, Label::GroundSpeed);
+        assert_eq!(decoded.data, 2000);
+        assert_eq!(decoded.ssm, Ssm::NormalOperation);
+
+        assert!(decode_unchecked(0xE01F4050).1);
+    }
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(Label::GroundSpeed.data_type(), DataType::Bnr);
+        assert_eq!(Label::RollAngle.data_type(), DataType::Bnr);
+        assert_eq!(Label::Date.data_type(), DataType::Bcd);
+        assert_eq!(Label::UtcTime.data_type(), DataType::Bcd);
+        assert_eq!(Label::Unknown(1).data_type(), DataType::Discrete);
+
+        let date = decode(encode(176, 0, 0x0C126, 3).unwrap()).unwrap();
+        assert_eq!(date.to_physical(), None);
+    }
+
+    #[test]
+    fn test_resolution() {
+        assert_eq!(Label::GroundSpeed.resolution(), Some(0.125));
+        assert_eq!(Label::Mach.resolution(), Some(0.001));
+        assert_eq!(Label::Tat.resolution(), Some(0.25));
+        assert_eq!(Label::RollAngle.resolution(), Some(0.01));
+        assert_eq!(Label::Date.resolution(), None);
+        assert_eq!(Label::Unknown(0).resolution(), None);
+    }
+
+    #[test]
+    fn test_range() {
+        assert_eq!(Label::GroundSpeed.range(), Some((0.0, 65535.875)));
+        assert_eq!(Label::Tat.range(), Some((-65536.0, 65535.75)));
+        assert_eq!(Label::Date.range(), None);
+
+        let (_, max) = Label::GroundSpeed.range().unwrap();
+        assert!(encode_physical(Label::GroundSpeed, max, 0, Ssm::NormalOperation).is_ok());
+        assert!(encode_physical(Label::GroundSpeed, max + 1.0, 0, Ssm::NormalOperation).is_err());
+        assert!(encode_physical(Label::GroundSpeed, f64::NAN, 0, Ssm::NormalOperation).is_err());
+    }
+
+    #[test]
+    fn test_sign_extend_short_field() {
+        // 12-bit field: sign bit is bit 11, not the fixed bit 18
+        assert_eq!(sign_extend(0x800, 12), -2048);
+        assert_eq!(sign_extend(0xFFF, 12), -1);
+        assert_eq!(sign_extend(0x7FF, 12), 2047);
+        // Bits above the used field must not affect the result once masked
+        assert_eq!(sign_extend(0x40000 & field_mask(12), 12), 0);
+
+        assert_eq!(sign_extend(0x7FFFF, 19), -1);
+        assert_eq!(sign_extend(0x3FFFF, 19), 262143);
+    }
+
+    #[test]
+    fn test_gnss_position_uses_sdi_bits() {
+        let lsb = Label::GnssLatitude.resolution().unwrap();
+        assert_eq!(lsb, 180.0 / 1048576.0);
+        assert_eq!(Label::GnssLongitude.range(), Some((-180.0, 180.0 - lsb)));
+
+        // 21-bit count 0x40003: bits 9-10 hold the low 0b11.
+        let raw = encode(0o110, 3, 0x10000, 3).unwrap();
+        let word = decode(raw).unwrap();
+        assert_eq!(
+            (word.label, word.sdi, word.data),
+            (Label::GnssLatitude, 0, 0x40003)
+        );
+        assert_eq!(word.to_physical(), Some(0x40003 as f64 * lsb));
+        assert_eq!(word.bits(11..=29), 0x10000);
+        assert_eq!(word.to_word(), Ok(raw));
+
+        let view = WordView::from_raw_checked(raw).unwrap();
+        assert_eq!((view.sdi(), view.data()), (0, 0x40003));
+
+        // Encoding fills the SDI bits from the value, whatever SDI is asked for.
+        let west = -(122.0 + 3.0 * lsb);
+        let raw = encode_physical(Label::GnssLongitude, west, 2, Ssm::NormalOperation).unwrap();
+        let word = decode(raw).unwrap();
+        assert_eq!(word.sdi, 0);
+        assert!((word.to_physical().unwrap() - west).abs() < lsb / 2.0);
+        #[cfg(feature = "alloc")]
+        assert_eq!(LabelTable::builtin().decode(raw), Ok(word));
+
+        // Coarse present position keeps its SDI.
+        assert_eq!(decode(encode(0o310, 3, 0x100, 3).unwrap()).unwrap().sdi, 3);
+    }
+
+    #[test]
+    fn test_short_field_sign_bit() {
+        // Vertical speed uses 11 bits: bit 10 is the sign, bits 11..=18 are not data.
+        let physical = |data| {
+            decode(encode(Label::VerticalSpeed.raw(), 0, data, 3).unwrap())
+                .unwrap()
+                .to_physical()
+        };
+        assert_eq!(physical(0x400), Some(-1024.0 * 16.0));
+        assert_eq!(physical(0x3FF), Some(1023.0 * 16.0));
+        assert_eq!(physical(0x7F800 | 0x001), Some(16.0));
+
+        // A 12-bit field takes its sign from bit 11 whatever bit 18 says.
+        let spec = LabelSpec::bnr("Cabin Altitude", "feet", 4.0, true, 12);
+        assert_eq!(spec.scale(0x800), Some(-2048.0 * 4.0));
+        assert_eq!(spec.scale(0x40FFF), Some(-4.0));
+        assert_eq!(spec.scale(0x407FF), Some(2047.0 * 4.0));
+    }
+
+    #[test]
+    fn test_builder() {
+        let word = ArincWordBuilder::new(Label::GroundSpeed)
+            .data(2000)
+            .build()
+            .unwrap();
+        assert_eq!(word, 0xE01F4050);
+
+        let word = ArincWordBuilder::new(Label::Tat)
+            .sdi(2)
+            .ssm(Ssm::FunctionalTest)
+            .data(1)
+            .physical(-50.0)
+            .build()
+            .unwrap();
+        assert_eq!(
+            word,
+            encode_physical(Label::Tat, -50.0, 2, Ssm::FunctionalTest).unwrap()
+        );
+
+        let builder = ArincWordBuilder::new(Label::GroundSpeed)
+            .physical(1.0)
+            .data(2000);
+        assert_eq!(builder.build(), Ok(0xE01F4050));
+
+        assert_eq!(
+            ArincWordBuilder::new(Label::GroundSpeed).sdi(4).build(),
+            Err(ArincError::InvalidSdi(4))
+        );
+    }
+
+    #[test]
+    fn test_decode_slice() {
+        let words = [
+            encode(10, 0, 2000, 3).unwrap(),
+            encode(131, 1, 25000, 3).unwrap(),
+            encode(212, 2, 4500, 3).unwrap(),
+        ];
+        let mut bytes: Vec<u8> = words.iter().flat_map(|w| w.to_le_bytes()).collect();
+
+        let decoded: Vec<_> = decode_slice(&bytes).collect();
+        assert_eq!(decoded.len(), 3);
+        for (result, word) in decoded.into_iter().zip(words) {
+            assert_eq!(result, decode(word));
+        }
+
+        bytes.extend_from_slice(&[0xAA, 0xBB]);
+        assert_eq!(
+            decode_slice(&bytes).last(),
+            Some(Err(ArincError::TruncatedBuffer { remaining: 2 }))
+        );
+    }
+
+    #[test]
+    fn test_encode_to_bytes() {
+        assert_eq!(
+            encode_to_bytes(10, 0, 2000, 3, Endian::Big),
+            Ok([0xE0, 0x1F, 0x40, 0x50])
+        );
+        assert_eq!(
+            encode_to_bytes(10, 0, 2000, 3, Endian::Little),
+            Ok([0x50, 0x40, 0x1F, 0xE0])
+        );
+
+        let bytes = encode_to_bytes(10, 0, 2000, 3, Endian::Little).unwrap();
+        assert_eq!(decode_slice(&bytes).next(), Some(decode(0xE01F4050)));
+    }
+
+    #[test]
+    fn test_vertical_speed() {
+        for fpm in [2000.0, -2000.0] {
+            let word = encode_physical(Label::VerticalSpeed, fpm, 0, Ssm::NormalOperation).unwrap();
+            let decoded = decode(word).unwrap();
+            assert_eq!(decoded.label, Label::VerticalSpeed);
+            assert_eq!(decoded.to_physical(), Some(fpm));
+        }
+
+        // 11-bit field: sign is bit 10, so -2000 / 16 = -125 is 0x783
+        let word = encode(Label::VerticalSpeed.raw(), 0, 0x783, 3).unwrap();
+        assert_eq!(decode(word).unwrap().to_physical(), Some(-2000.0));
+        assert_eq!(Label::VerticalSpeed.range(), Some((-16384.0, 16368.0)));
+    }
+
+    #[test]
+    fn test_heading_wraps() {
+        let word =
+            encode_physical(Label::MagneticHeading, 359.99, 0, Ssm::NormalOperation).unwrap();
+        let heading = decode(word).unwrap().to_physical().unwrap();
+        assert!((heading - 359.99).abs() < 1e-9);
+
+        let word = encode_physical(Label::TrueHeading, 0.01, 0, Ssm::NormalOperation).unwrap();
+        assert_eq!(decode(word).unwrap().to_physical(), Some(0.01));
+
+        // -0.01° (all ones) is the same direction as 359.99°
+        let word = encode(Label::TrueHeading.raw(), 0, 0x7FFFF, 3).unwrap();
+        let heading = decode(word).unwrap().to_physical().unwrap();
+        assert!((heading - 359.99).abs() < 1e-9);
+        assert_eq!(
+            Label::from_octal_str("320").unwrap(),
+            Label::MagneticHeading
+        );
+        assert_eq!(Label::MagneticHeading.units(), "°");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_pitch_and_flight_path_angle() {
+        for label in [Label::PitchAngle, Label::FlightPathAngle] {
+            for degrees in [-10.0, 15.0] {
+                let word = encode_physical(label, degrees, 0, Ssm::NormalOperation).unwrap();
+                let decoded = decode(word).unwrap();
+                assert_eq!(decoded.label, label);
+                assert_eq!(decoded.to_physical(), Some(degrees));
+            }
+        }
+        assert_eq!(Label::PitchAngle.octal(), "325");
+        assert_eq!(Label::from_u8(213), Label::PitchAngle);
+    }
+
+    #[test]
+    fn test_angle_of_attack_and_sideslip() {
+        for label in [Label::AngleOfAttack, Label::SideslipAngle] {
+            for degrees in [-2.0, 18.0] {
+                let word = encode_physical(label, degrees, 0, Ssm::NormalOperation).unwrap();
+                let decoded = decode(word).unwrap();
+                assert_eq!(decoded.label, label);
+                assert_eq!(decoded.to_physical(), Some(degrees));
+            }
+        }
+
+        // -2.0° is -40 counts in two's complement
+        let word = encode(Label::AngleOfAttack.raw(), 0, 0x80000 - 40, 3).unwrap();
+        assert_eq!(decode(word).unwrap().to_physical(), Some(-2.0));
+    }
+
+    #[test]
+    fn test_discrete_bits() {
+        // Discrete word with ARINC bits 11, 13 and 27-29 set
+        let data = 0b111 << 16 | 0b101;
+        let word = decode(encode(0o270, 0, data, 3).unwrap()).unwrap();
+
+        assert_eq!(word.bit(11), Some(true));
+        assert_eq!(word.bit(12), Some(false));
+        assert_eq!(word.bit(13), Some(true));
+        assert_eq!(word.bit(29), Some(true));
+        assert_eq!(word.bit(10), None);
+        assert_eq!(word.bit(30), None);
+
+        assert_eq!(word.bits(11..=13), 0b101);
+        assert_eq!(word.bits(27..=29), 0b111);
+        assert_eq!(word.bits(11..=29), data);
+    }
+
+    #[test]
+    fn test_bcd_ssm() {
+        let plus = decode(encode(176, 0, 0x0C126, 0).unwrap()).unwrap();
+        assert_eq!(plus.ssm, Ssm::FailureWarning);
+        assert_eq!(plus.bcd_ssm(), Some(BcdSsm::Plus));
+        assert_eq!(plus.bcd_sign(), Some(1));
+
+        let minus = decode(encode(176, 0, 0x0C126, 3).unwrap()).unwrap();
+        assert_eq!(minus.bcd_ssm(), Some(BcdSsm::Minus));
+        assert_eq!(minus.bcd_sign(), Some(-1));
+
+        let ncd = decode(encode(104, 0, 0, 1).unwrap()).unwrap();
+        assert_eq!(ncd.bcd_ssm(), Some(BcdSsm::NoComputedData));
+        assert_eq!(ncd.bcd_sign(), None);
+
+        let bnr = decode(0xE01F4050).unwrap();
+        assert_eq!(bnr.bcd_ssm(), None);
+        assert_eq!(bnr.bcd_sign(), None);
+    }
+
+    #[test]
+    fn test_bcd_validation_edge_cases() {
+        let date = |data| decode(encode(176, 0, data, 3).unwrap()).unwrap().to_date();
+        assert_eq!(
+            date(0x0C126),
+            Some(BcdDate {
+                day: 6,
+                month: 1,
+                year: 26
+            })
+        );
+        assert_eq!(date(0x0C026), None); // month 00
+        assert_eq!(date(0x00126), None); // day 00
+        assert_eq!(date(0x0C12A), None); // year units > 9
+
+        let time = |data| decode(encode(104, 0, data, 3).unwrap()).unwrap().to_time();
+        assert_eq!(
+            time(0),
+            Some(BcdTime {
+                hour: 0,
+                minute: 0,
+                second: 0
+            })
+        );
+        assert_eq!(time(0x60), None); // second tens > 5
+    }
+
+    #[test]
+    fn test_cross_py_ground_speed() {
+        let word: u32 = 0xE01F4050;
+        let decoded = decode(word).unwrap();
+        assert_eq!(decoded.label, Label::GroundSpeed);
+        assert_eq!(decoded.ssm, Ssm::NormalOperation);
+        assert_eq!(decoded.to_physical(), Some(250.0));
+    }
+
+    #[test]
+    fn test_encode_physical_roundtrip() {
+        let word = encode_physical(Label::Tat, -50.0, 0, Ssm::NormalOperation).unwrap();
+        assert_eq!(decode(word).unwrap().to_physical(), Some(-50.0));
+
+        let word = encode_physical(Label::GroundSpeed, 250.0, 0, Ssm::NormalOperation).unwrap();
+        assert_eq!(word, 0xE01F4050);
+
+        assert_eq!(
+            encode_physical(Label::Mach, -0.5, 0, Ssm::NormalOperation),
+            Err(ArincError::ValueOutOfRange {
+                label: Label::Mach,
+                value: -0.5
+            })
+        );
+        assert_eq!(
+            encode_physical(Label::Date, 1.0, 0, Ssm::NormalOperation),
+            Err(ArincError::UnsupportedLabel(Label::Date))
+        );
+    }
+
+    #[test]
+    fn test_to_physical_raw_ignores_ssm() {
+        let test_word = ArincWord {
+            label: Label::PressureAltitude,
+            sdi: 0,
+            data: 1000,
+            ssm: Ssm::FunctionalTest,
+        };
+        assert_eq!(test_word.to_physical(), None);
+        assert_eq!(test_word.to_physical_raw(), Some(1000.0));
+
+        let heading = ArincWord {
+            label: Label::TrueHeading,
+            ssm: Ssm::NoComputedData,
+            ..decode(encode_physical(Label::TrueHeading, 350.0, 0, Ssm::NormalOperation).unwrap())
+                .unwrap()
+        };
+        assert!((heading.to_physical_raw().unwrap() - 350.0).abs() < 0.01);
+
+        let date = ArincWord {
+            label: Label::Date,
+            sdi: 0,
+            data: 1,
+            ssm: Ssm::FunctionalTest,
+        };
+        assert_eq!(date.to_physical_raw(), None);
+    }
+
+    #[test]
+    fn test_to_physical_in() {
+        // FL350 is 35,000 ft (10,668 m); 250 kt is 463 km/h.
+        let fl350 = decode(
+            encode_physical(Label::PressureAltitude, 35000.0, 0, Ssm::NormalOperation).unwrap(),
+        )
+        .unwrap();
+        assert!((fl350.to_physical_in(Unit::Meters).unwrap() - 10668.0).abs() < 1e-9);
+        assert_eq!(fl350.to_physical_in(Unit::Knots), None);
+
+        let speed = decode(0xE01F4050).unwrap();
+        assert!((speed.to_physical_in(Unit::KmH).unwrap() - 463.0).abs() < 1e-9);
+        assert_eq!(speed.to_physical_in(Unit::Knots), Some(250.0));
+
+        let ncd = ArincWord {
+            ssm: Ssm::NoComputedData,
+            ..speed
+        };
+        assert_eq!(ncd.to_physical_in(Unit::KmH), None);
+    }
+
+    #[test]
+    fn test_encode_physical_checked_reports_quantization() {
+        let (word, error) =
+            encode_physical_checked(Label::Mach, 0.8005, 0, Ssm::NormalOperation).unwrap();
+        assert!((error.abs() - 0.0005).abs() < 1e-9);
+        let encoded = decode(word).unwrap().to_physical().unwrap();
+        assert!((0.8005 - encoded - error).abs() < 1e-12);
+        assert_eq!(
+            word,
+            encode_physical(Label::Mach, 0.8005, 0, Ssm::NormalOperation).unwrap()
+        );
+
+        let (word, error) =
+            encode_physical_checked(Label::Tat, -10.1, 0, Ssm::NormalOperation).unwrap();
+        assert_eq!(decode(word).unwrap().to_physical(), Some(-10.0));
+        assert!((error + 0.1).abs() < 1e-9);
+
+        assert_eq!(
+            encode_physical_checked(Label::Date, 1.0, 0, Ssm::NormalOperation),
+            Err(ArincError::UnsupportedLabel(Label::Date))
+        );
+    }
+
+    #[test]
+    fn test_get_field_arinc_numbering() {
+        let word = encode(0o012, 2, 2000, 3).unwrap();
+        assert_eq!(get_field(word, 8, 1), u32::from(0o012u8.reverse_bits()));
+        assert_eq!(get_field(word, 10, 9), 2);
+        assert_eq!(get_field(word, 29, 11), 2000);
+        assert_eq!(get_field(word, 31, 30), 3);
+        assert_eq!(get_field(word, 32, 32), word >> 31);
+        assert_eq!(get_field(word, 32, 1), word);
+    }
+
+    #[test]
+    fn test_set_field_arinc_numbering() {
+        let word = encode(0o012, 0, 2000, 3).unwrap();
+        let ncd = set_field(word, 31, 30, Ssm::NoComputedData.as_u8().into());
+        assert_eq!(get_field(ncd, 31, 30), 1);
+        assert_eq!(get_field(ncd, 29, 1), get_field(word, 29, 1));
+
+        let relabelled = set_field(0, 8, 1, u32::from(0o203u8.reverse_bits()));
+        assert_eq!(relabelled, 0b1100_0001);
+        assert_eq!(set_field(0, 10, 9, 0xFF), 0b11 << 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the word")]
+    fn test_get_field_rejects_bit_zero() {
+        get_field(0, 8, 0);
+    }
+
+    #[test]
+    fn test_fix_parity_makes_any_word_decodable() {
+        let mut pattern = 0x1234_5678u32;
+        for _ in 0..1000 {
+            // xorshift32 to cover arbitrary lower-31-bit patterns
+            pattern ^= pattern << 13;
+            pattern ^= pattern >> 17;
+            pattern ^= pattern << 5;
+            let fixed = fix_parity(pattern);
+            assert!(check_parity(fixed));
+            assert_eq!(fixed & 0x7FFF_FFFF, pattern & 0x7FFF_FFFF);
+            assert!(decode(fixed).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_check_parity_matches_encode() {
+        let word = encode(0o203, 0, 1000, 3).unwrap();
+        assert!(check_parity(word));
+        assert!(!check_parity(word ^ (1 << 31)));
+        assert_eq!(fix_parity(word ^ (1 << 31)), word);
+    }
+
+    #[test]
+    fn test_all_labels_roundtrip() {
+        for &label in Label::all() {
+            assert_eq!(Label::from_u8(label.raw()), label, "{:?}", label);
+            let mut buf = [0u8; 3];
+            #[cfg(feature = "alloc")]
+            assert_eq!(label.octal(), label.octal_to(&mut buf), "{:?}", label);
+            assert_eq!(
+                Label::from_octal_str(label.octal_to(&mut buf)),
+                Ok(label),
+                "{:?}",
+                label
+            );
+        }
+    }
+
+    #[test]
+    fn test_label_info_table() {
+        for info in LABEL_INFO {
+            let label = info.label;
+            assert_eq!(label.info().map(|i| i.name), Some(info.name));
+            assert_eq!(
+                info.data_type == DataType::Bnr,
+                info.resolution.is_some() && info.significant_bits.is_some(),
+                "{:?}",
+                label
+            );
+            // A known code wrapped in `Unknown` is not looked up.
+            assert!(Label::Unknown(label.raw()).info().is_none());
+        }
+        assert_eq!(Label::all().len(), LABEL_INFO.len());
+    }
+
+    #[test]
+    fn test_transmission_interval() {
+        let ms = |m: f64| Duration::from_secs_f64(m / 1000.0);
+        assert_eq!(
+            Label::GroundSpeed.transmission_interval(),
+            Some((ms(25.0), ms(50.0)))
+        );
+        assert_eq!(
+            Label::PressureAltitude.transmission_interval(),
+            Some((ms(31.25), ms(62.5)))
+        );
+        assert_eq!(
+            Label::RollAngle.transmission_interval(),
+            Some((ms(25.0), ms(50.0)))
+        );
+        assert_eq!(Label::Unknown(0o377).transmission_interval(), None);
+        for label in Label::all() {
+            let (min, max) = label.transmission_interval().unwrap();
+            assert!(min < max, "{:?}", label);
+        }
+    }
+
+    #[test]
+    fn test_sdi_enum() {
+        for raw in 0..=3 {
+            assert_eq!(Sdi::from_u8(raw).unwrap().as_u8(), raw);
+        }
+        assert_eq!(Sdi::from_u8(4), Err(ArincError::InvalidSdi(4)));
+
+        let word = encode_with_sdi(0o012, Sdi::Two, 2000, 3).unwrap();
+        assert_eq!(word, encode(0o012, 2, 2000, 3).unwrap());
+        assert_eq!(decode(word).unwrap().sdi_enum(), Sdi::Two);
+        assert_eq!(
+            decode(encode(0o012, 0, 0, 3).unwrap()).unwrap().sdi_enum(),
+            Sdi::All
+        );
+    }
+
+    #[test]
+    fn test_ssm_severity() {
+        assert_eq!(Ssm::NormalOperation.severity(), Severity::Ok);
+        assert_eq!(Ssm::FunctionalTest.severity(), Severity::Info);
+        assert_eq!(Ssm::NoComputedData.severity(), Severity::Warning);
+        assert_eq!(Ssm::FailureWarning.severity(), Severity::Error);
+        assert!(Severity::Ok < Severity::Info && Severity::Warning < Severity::Error);
+    }
+
+    #[test]
+    fn test_ssm_validity_order() {
+        let ranked = [
+            Ssm::FailureWarning,
+            Ssm::NoComputedData,
+            Ssm::FunctionalTest,
+            Ssm::NormalOperation,
+        ];
+        for (i, a) in ranked.iter().enumerate() {
+            for (j, b) in ranked.iter().enumerate() {
+                assert_eq!(a.cmp(b), i.cmp(&j), "{:?} vs {:?}", a, b);
+            }
+        }
+        assert_eq!(ranked.iter().max(), Some(&Ssm::NormalOperation));
+    }
+
+    #[test]
+    fn test_prefer_higher_validity() {
+        let word = |ssm: Ssm, data: u32| ArincWord {
+            label: Label::GroundSpeed,
+            sdi: 0,
+            data,
+            ssm,
+        };
+        let normal = word(Ssm::NormalOperation, 1);
+        let test = word(Ssm::FunctionalTest, 2);
+        let ncd = word(Ssm::NoComputedData, 3);
+        let fail = word(Ssm::FailureWarning, 4);
+
+        assert_eq!(ArincWord::prefer(&fail, &normal), &normal);
+        assert_eq!(ArincWord::prefer(&normal, &test), &normal);
+        assert_eq!(ArincWord::prefer(&ncd, &test), &test);
+        assert_eq!(ArincWord::prefer(&ncd, &fail), &ncd);
+
+        // Ties keep the first argument.
+        let other = word(Ssm::NormalOperation, 9);
+        assert_eq!(ArincWord::prefer(&normal, &other).data, 1);
+        assert_eq!(ArincWord::prefer(&other, &normal).data, 9);
+    }
+
+    #[test]
+    fn test_equipment_id_dadc() {
+        // Label 377, equipment ID 006 (DADC), unused bits 23-29 set, SSM 00
+        let word = encode(0o377, 0, (0x7F << 12) | 0x006, 0).unwrap();
+        let decoded = decode(word).unwrap();
+        assert_eq!(decoded.label, Label::EquipmentIdentification);
+        assert_eq!(decoded.to_equipment_id(), Some(EquipmentId::DADC.0));
+        assert_eq!(decoded.to_physical(), None);
+
+        let other = decode(encode(0o012, 0, 0x006, 3).unwrap()).unwrap();
+        assert_eq!(other.to_equipment_id(), None);
+    }
+
+    #[test]
+    fn test_selected_altitude_and_heading() {
+        let fl350 =
+            encode_physical(Label::SelectedAltitude, 35000.0, 0, Ssm::NormalOperation).unwrap();
+        let decoded = decode(fl350).unwrap();
+        assert_eq!(decoded.label, Label::SelectedAltitude);
+        assert_eq!(decoded.data, 35000);
+        assert_eq!(decoded.to_physical(), Some(35000.0));
+
+        let hdg = encode_physical(Label::SelectedHeading, 270.0, 0, Ssm::NormalOperation).unwrap();
+        let decoded = decode(hdg).unwrap();
+        assert_eq!(decoded.label, Label::SelectedHeading);
+        assert_eq!(decoded.label.octal_to(&mut [0; 3]), "101");
+        assert!((decoded.to_physical().unwrap() - 270.0).abs() < 1e-9);
+
+        // -90° is reported as 270°, like the measured headings.
+        let west = encode_physical(Label::SelectedHeading, -90.0, 0, Ssm::NormalOperation).unwrap();
+        assert!((decode(west).unwrap().to_physical().unwrap() - 270.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_static_air_temperature_and_indicated_airspeed() {
+        // Tropopause standard temperature
+        let sat =
+            encode_physical(Label::StaticAirTemperature, -56.5, 0, Ssm::NormalOperation).unwrap();
+        let decoded = decode(sat).unwrap();
+        assert_eq!(decoded.label, Label::StaticAirTemperature);
+        assert_eq!(decoded.data, 0x80000 - 226);
+        assert_eq!(decoded.to_physical(), Some(-56.5));
+
+        let ias =
+            encode_physical(Label::IndicatedAirspeed, 280.0, 0, Ssm::NormalOperation).unwrap();
+        let decoded = decode(ias).unwrap();
+        assert_eq!(decoded.label, Label::IndicatedAirspeed);
+        assert_eq!(decoded.data, 4480);
+        assert_eq!(decoded.to_physical(), Some(280.0));
+        assert_eq!(Label::from_octal_str("206"), Ok(Label::IndicatedAirspeed));
+    }
+
+    #[test]
+    fn test_engine_labels() {
+        let n1 = encode_physical(Label::EngineN1, 92.5, 0, Ssm::NormalOperation).unwrap();
+        let decoded = decode(n1).unwrap();
+        assert_eq!(decoded.label, Label::EngineN1);
+        assert_eq!(decoded.data, 1480);
+        assert_eq!(decoded.to_physical(), Some(92.5));
+
+        let n2 = encode_physical(Label::EngineN2, 98.0, 1, Ssm::NormalOperation).unwrap();
+        assert_eq!(decode(n2).unwrap().label, Label::EngineN2);
+
+        let egt = encode_physical(Label::Egt, 650.0, 0, Ssm::NormalOperation).unwrap();
+        assert_eq!(decode(egt).unwrap().to_physical(), Some(650.0));
+
+        // Cold engine in winter
+        let cold = encode_physical(Label::Egt, -20.0, 0, Ssm::NormalOperation).unwrap();
+        assert_eq!(decode(cold).unwrap().to_physical(), Some(-20.0));
+    }
+
+    #[test]
+    fn test_dme_distance() {
+        let word = encode_physical(Label::DmeDistance, 12.5, 0, Ssm::NormalOperation).unwrap();
+        let decoded = decode(word).unwrap();
+        assert_eq!(decoded.label, Label::DmeDistance);
+        assert_eq!(decoded.data, 1250);
+        assert_eq!(decoded.to_physical(), Some(12.5));
+    }
+
+    #[test]
+    fn test_ils_deviations_are_bipolar() {
+        let deviation = |label, ddm| {
+            let word = encode_physical(label, ddm, 0, Ssm::NormalOperation).unwrap();
+            decode(word).unwrap()
+        };
+
+        // Right of the localizer course: fly left (positive)
+        let fly_left = deviation(Label::LocalizerDeviation, 0.0155);
+        assert_eq!(fly_left.data, 155);
+        assert!((fly_left.to_physical().unwrap() - 0.0155).abs() < 1e-12);
+
+        // Left of course: fly right (negative, two's complement)
+        let fly_right = deviation(Label::LocalizerDeviation, -0.0155);
+        assert_eq!(fly_right.data, 0x80000 - 155);
+        assert!((fly_right.to_physical().unwrap() + 0.0155).abs() < 1e-12);
+
+        // Above the glidepath: fly down (positive); below: fly up (negative)
+        let above = deviation(Label::GlideslopeDeviation, 0.0875);
+        let below = deviation(Label::GlideslopeDeviation, -0.0875);
+        assert_eq!(above.label, Label::GlideslopeDeviation);
+        assert!(above.to_physical().unwrap() > 0.0);
+        assert!(below.to_physical().unwrap() < 0.0);
+        assert!((above.to_physical().unwrap() + below.to_physical().unwrap()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_radio_altitude() {
+        let word = encode_physical(Label::RadioAltitude, 50.0, 0, Ssm::NormalOperation).unwrap();
+        let decoded = decode(word).unwrap();
+        assert_eq!(decoded.label, Label::RadioAltitude);
+        assert_eq!(decoded.data, 400);
+        assert_eq!(decoded.to_physical(), Some(50.0));
+
+        let ncd = decode(encode(0o164, 0, 400, Ssm::NoComputedData.as_u8()).unwrap()).unwrap();
+        assert_eq!(ncd.to_physical(), None);
+
+        assert_eq!(Label::RadioAltitude.range(), Some((-8192.0, 8191.875)));
+        assert!(matches!(
+            encode_physical(Label::RadioAltitude, 10_000.0, 0, Ssm::NormalOperation),
+            Err(ArincError::ValueOutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_wind() {
+        let speed = encode_physical(Label::WindSpeed, 45.0, 0, Ssm::NormalOperation).unwrap();
+        let direction =
+            encode_physical(Label::WindDirection, 270.0, 0, Ssm::NormalOperation).unwrap();
+        assert_eq!(
+            decode(speed).unwrap().label,
+            Label::from_octal_code(315).unwrap()
+        );
+        assert_eq!(decode(speed).unwrap().to_physical(), Some(45.0));
+        assert_eq!(decode(direction).unwrap().to_physical(), Some(270.0));
+        assert_eq!(Label::WindSpeed.range(), Some((0.0, 255.0)));
+    }
+
+    #[test]
+    fn test_wind_direction_wraps_at_north() {
+        let decoded = |degrees| {
+            let word = encode_physical(Label::WindDirection, degrees, 0, Ssm::NormalOperation);
+            decode(word.unwrap()).unwrap().to_physical().unwrap()
+        };
+        assert_eq!(decoded(0.0), 0.0);
+        assert_eq!(decoded(360.0), 0.0);
+        assert!((decoded(359.99) - 359.99).abs() < 1e-9);
+        assert!((decoded(-0.01) - 359.99).abs() < 1e-9);
+        assert!((decoded(-90.0) - 270.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fuel_labels() {
+        let flow = decode(encode(0o244, 1, 2400, 3).unwrap()).unwrap();
+        assert_eq!(flow.label, Label::FuelFlow);
+        assert_eq!(flow.to_physical(), Some(2400.0));
+        assert_eq!(Label::FuelFlow.units(), "lb/h");
+
+        let total = decode(encode(0o247, 0, 1500, 3).unwrap()).unwrap();
+        assert_eq!(total.label, Label::FuelQuantity);
+        assert_eq!(total.to_physical(), Some(30_000.0));
+        assert_eq!(Label::FuelQuantity.units(), "lb");
+        let kg = total.to_physical_in(Unit::Kilograms).unwrap();
+        assert!((kg - 13607.7711).abs() < 1e-9);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode_batch() {
+        let words = encode_batch(
+            &[
+                (Label::GroundSpeed, 250.0),
+                (Label::Mach, 0.8),
+                (Label::Tat, -50.0),
+                (Label::Date, 1.0),
+            ],
+            1,
+            Ssm::NormalOperation,
+        );
+        let labels: Vec<_> = words.iter().map(|(label, _)| *label).collect();
+        assert_eq!(
+            labels,
+            [Label::GroundSpeed, Label::Mach, Label::Tat, Label::Date]
+        );
+        let values: Vec<_> = words[..3]
+            .iter()
+            .map(|(_, word)| decode(*word.as_ref().unwrap()).unwrap())
+            .map(|word| (word.sdi, word.to_physical().unwrap()))
+            .collect();
+        assert_eq!(values, [(1, 250.0), (1, 0.8), (1, -50.0)]);
+        assert_eq!(words[3].1, Err(ArincError::UnsupportedLabel(Label::Date)));
+    }
+
+    #[test]
+    fn test_const_encode_decode() {
+        const WORD: u32 = encode_raw(0o203, 1, 25000, 3);
+        const DECODED: ArincWord = match decode(WORD) {
+            Ok(word) => word,
+            Err(_) => panic!("const word has bad parity"),
+        };
+        const TABLE: [u32; 2] = [encode_raw(0o012, 0, 2000, 3), fix_parity(0x50)];
+
+        assert_eq!(Ok(WORD), encode(0o203, 1, 25000, 3));
+        assert_eq!(DECODED.label, Label::PressureAltitude);
+        assert_eq!(DECODED.to_physical(), Some(25000.0));
+        assert_eq!(TABLE[0], 0xE01F4050);
+        assert!(check_parity(TABLE[1]));
+    }
+
+    #[test]
+    fn test_encode_raw_masks_fields() {
+        assert_eq!(
+            encode_raw(0o012, 4, 0x8_0000 | 2000, 7),
+            encode_raw(0o012, 0, 2000, 3)
+        );
+    }
+
+    #[test]
+    fn test_reverse_table_matches_reverse_bits() {
+        for byte in 0..=u8::MAX {
+            assert_eq!(
+                REVERSE[usize::from(byte)],
+                byte.reverse_bits(),
+                "{:#04x}",
+                byte
+            );
+        }
+    }
+
+    #[test]
+    fn test_octal_formatting_without_allocation() {
+        assert_eq!(&Label::Unknown(8).octal_bytes(), b"010");
+        assert_eq!(Label::Unknown(8).octal_to(&mut [0; 3]), "010");
+        assert_eq!(&Label::Unknown(255).octal_bytes(), b"377");
+
+        struct Sink([u8; 8], usize);
+        impl fmt::Write for Sink {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0[self.1..self.1 + s.len()].copy_from_slice(s.as_bytes());
+                self.1 += s.len();
+                Ok(())
+            }
+        }
+        let mut sink = Sink([0; 8], 0);
+        Label::Unknown(8).write_octal(&mut sink).unwrap();
+        Label::Mach.write_octal(&mut sink).unwrap();
+        assert_eq!(&sink.0[..sink.1], b"010205");
+    }
+
+    #[test]
+    fn test_decode_with_hook() {
+        // 377 is Equipment Identification, so use an unassigned label instead.
+        assert!(!matches!(Label::from_u8(0o377), Label::Unknown(_)));
+        let mut seen = Vec::new();
+        for word in [
+            encode(0o277, 1, 5, 3).unwrap(),
+            encode(0o377, 0, 0, 3).unwrap(),
+            encode(0o277, 2, 5, 3).unwrap() ^ (1 << 31),
+        ] {
+            let _ = decode_with_hook(word, |code| seen.push(code));
+        }
+        assert_eq!(seen, [0o277]);
+    }
+
+    #[test]
+    fn test_combine_extended_26_bits() {
+        let value = 0x2ABCDEF; // 26 bits
+        let msw = decode(encode(0o100, 2, value >> 19, 3).unwrap()).unwrap();
+        let lsw = decode(encode(0o100, 2, value & 0x7FFFF, 3).unwrap()).unwrap();
+        assert_eq!(combine_extended(&msw, &lsw, 7), Some(value));
+        // Extra MSW bits above `msw_bits` are ignored.
+        let noisy = decode(encode(0o100, 2, 0x7FF80 | value >> 19, 3).unwrap()).unwrap();
+        assert_eq!(combine_extended(&noisy, &lsw, 7), Some(value));
+
+        let other_sdi = decode(encode(0o100, 1, value & 0x7FFFF, 3).unwrap()).unwrap();
+        let other_label = decode(encode(0o101, 2, value & 0x7FFFF, 3).unwrap()).unwrap();
+        assert_eq!(combine_extended(&msw, &other_sdi, 7), None);
+        assert_eq!(combine_extended(&msw, &other_label, 7), None);
+        assert_eq!(combine_extended(&msw, &lsw, 14), None);
+    }
+
+    #[test]
+    fn test_twos_complement_19_extremes() {
+        assert_eq!(to_twos_complement_19(262_143), Ok(0x3FFFF));
+        assert_eq!(to_twos_complement_19(-262_144), Ok(0x40000));
+        assert_eq!(from_twos_complement_19(0x3FFFF), 262_143);
+        assert_eq!(from_twos_complement_19(0x40000), -262_144);
+        assert_eq!(
+            from_twos_complement_19(to_twos_complement_19(-200).unwrap()),
+            -200
+        );
+        assert_eq!(
+            to_twos_complement_19(262_144),
+            Err(ArincError::DataOverflow(262_144))
+        );
+        assert!(to_twos_complement_19(-262_145).is_err());
+    }
+
+    #[test]
+    fn test_gray_to_binary() {
+        for value in (0..=0x7FFFF).step_by(97).chain([0x7FFFF, u32::MAX]) {
+            assert_eq!(gray_to_binary(value ^ (value >> 1)), value);
+        }
+        // Consecutive values differ in one bit once Gray-coded.
+        assert_eq!(gray_to_binary(0b0110), 4);
+        assert_eq!(gray_to_binary(0b0111), 5);
+    }
+
+    #[test]
+    fn test_to_physical_gray() {
+        // -45° roll: Gray code of the 19-bit two's complement count.
+        let counts = (-45.0f64 / Label::RollAngle.resolution().unwrap()).round() as i32 as u32;
+        let field = counts & 0x7FFFF;
+        let word = decode(encode(0o324, 1, field ^ (field >> 1), 3).unwrap()).unwrap();
+        assert!((word.to_physical_gray().unwrap() + 45.0).abs() < 1e-9);
+        assert_ne!(word.to_physical(), word.to_physical_gray());
+
+        let ncd = ArincWord {
+            ssm: Ssm::NoComputedData,
+            ..word
+        };
+        assert_eq!(ncd.to_physical_gray(), None);
+    }
+
+    #[test]
+    fn test_gray_column_feeds_to_physical() {
+        for label in KNOWN_LABELS {
+            assert_eq!(label.spec().unwrap().gray, label.is_gray_coded());
+        }
+
+        // A Gray-coded roll row scales the same word as to_physical_gray does.
+        let counts = (-45.0f64 / Label::RollAngle.resolution().unwrap()).round() as i32 as u32;
+        let field = counts & 0x7FFFF;
+        let word = decode(encode(0o324, 1, field ^ (field >> 1), 3).unwrap()).unwrap();
+        let row = LabelInfo::bnr(
+            Label::RollAngle,
+            "Roll Angle",
+            "°",
+            0.01,
+            19,
+            SIGNED,
+            (25_000, 50_000),
+        )
+        .gray_coded();
+        let gray = row.spec(false).value(&word).unwrap();
+        assert!((gray + 45.0).abs() < 1e-9);
+        assert_eq!(Some(gray), word.to_physical_gray());
+        assert_ne!(Some(gray), word.to_physical());
+    }
+
+    #[test]
+    fn test_peek_matches_decode() {
+        for label in 0..=255u8 {
+            for sdi in 0..4 {
+                let word = encode(label, sdi, 0x5A5A5, 3).unwrap();
+                let decoded = decode(word).unwrap();
+                assert_eq!(peek_label(word), decoded.label.raw());
+                if !decoded.label.sdi_is_data() {
+                    assert_eq!(peek_sdi(word), decoded.sdi);
+                }
+            }
+        }
+        // Parity is not checked.
+        assert_eq!(peek_label(0x601F4050), 0o012);
+    }
+
+    #[test]
+    fn test_ssm_raw_roundtrip() {
+        let word = encode(0o012, 0, 2000, 1).unwrap();
+        let decoded = decode(word).unwrap();
+        assert_eq!((decoded.ssm, decoded.ssm_raw()), (Ssm::NoComputedData, 1));
+        assert_eq!(decoded.to_word(), Ok(word));
+
+        // BCD label: SSM 00 is "plus", not Failure Warning as under the BNR convention.
+        let date = decode(encode(0o260, 0, 0x1234, 0).unwrap()).unwrap();
+        assert_eq!(date.ssm, Ssm::FailureWarning);
+        assert_eq!(BcdSsm::from_u8(date.ssm_raw()), BcdSsm::Plus);
+        for raw in 0..=3 {
+            assert_eq!(
+                decode(encode(0o012, 0, 0, raw).unwrap()).unwrap().ssm_raw(),
+                raw
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_fully_reversed_word() {
+        let word = encode(0o203, 2, 0x12345, 3).unwrap();
+        let captured = word.reverse_bits();
+        // Read in the standard order, the reversed capture is garbage.
+        assert_ne!(decode_unchecked(captured).0, decode_unchecked(word).0);
+
+        let decoded = decode_with_order(captured, WireOrder::Reversed).unwrap();
+        assert_eq!(decoded.label, Label::PressureAltitude);
+        assert_eq!(
+            (decoded.sdi, decoded.data, decoded.ssm),
+            (2, 0x12345, Ssm::NormalOperation)
+        );
+        assert_eq!(decode_with_order(word, WireOrder::default()), decode(word));
+        assert_eq!(WireOrder::Reversed.to_standard(captured), word);
+    }
+
+    #[test]
+    #[cfg(feature = "heapless")]
+    fn test_decode_into_heapless_stops_when_full() {
+        let words = [
+            encode(0o012, 0, 1, 3).unwrap(),
+            encode(0o012, 0, 2, 3).unwrap() ^ (1 << 31), // bad parity, skipped
+            encode(0o012, 0, 3, 3).unwrap(),
+            encode(0o012, 0, 4, 3).unwrap(),
+            encode(0o012, 0, 5, 3).unwrap(),
+        ];
+        let mut out = heapless::Vec::<ArincWord, 2>::new();
+        let err = decode_into_heapless(&words, &mut out).unwrap_err();
+        assert_eq!(err, CapacityError { consumed: 3 });
+        assert_eq!(out.iter().map(|w| w.data).collect::<Vec<_>>(), [1, 3]);
+
+        // Resume from where decoding stopped.
+        assert_eq!(
+            decode_into_heapless(&words[err.consumed..], &mut out),
+            Ok(())
+        );
+        assert_eq!(out.iter().map(|w| w.data).collect::<Vec<_>>(), [4, 5]);
+
+        let mut big = heapless::Vec::<ArincWord, 8>::new();
+        assert_eq!(decode_into_heapless(&words, &mut big), Ok(()));
+        assert_eq!(big.len(), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_latest_by_label_last_wins() {
+        let words = [
+            encode(0o012, 0, 1000, 3).unwrap(),
+            encode(0o203, 1, 500, 3).unwrap(),
+            encode(0o012, 2, 2000, 1).unwrap(),
+            encode(0o203, 1, 600, 3).unwrap() ^ (1 << 31), // bad parity, ignored
+        ];
+        let latest = latest_by_label(&words);
+        assert_eq!(latest.len(), 2);
+        assert_eq!((latest[&0o012].data, latest[&0o012].sdi), (2000, 2));
+        assert_eq!(latest[&0o012].ssm, Ssm::NoComputedData);
+        assert_eq!(latest[&0o203].data, 500);
+
+        let by_source = latest_by_key(&words, |w| (w.label.raw(), w.sdi));
+        assert_eq!(by_source.len(), 3);
+        assert_eq!(by_source[&(0o012, 0)].data, 1000);
+        assert_eq!(by_source[&(0o012, 2)].data, 2000);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_many_into_reuses_buffer() {
+        let words = [
+            encode(0o012, 0, 2000, 3).unwrap(),
+            encode(0o203, 1, 1000, 3).unwrap() ^ (1 << 31),
+        ];
+        let expected: Vec<_> = words.iter().map(|&w| decode(w)).collect();
+        assert_eq!(decode_many(&words), expected);
+
+        let mut out = Vec::with_capacity(8);
+        let capacity = out.capacity();
+        for _ in 0..3 {
+            decode_many_into(&words, &mut out);
+            assert_eq!(out, expected);
+        }
+        assert_eq!(out.capacity(), capacity);
+
+        decode_many_into(&[], &mut out);
+        assert!(out.is_empty());
     }
 }
@@ -0,0 +1,123 @@
+//! Typed encoding and decoding of user-defined parameters.
+
+use crate::{decode, encode, ArincError, Ssm};
+
+/// A strongly typed value carried in the data field of one label.
+///
+/// Implement it for application types to encode and decode them with [`encode_param`]
+/// and [`decode_param`] instead of handling raw data fields.
+///
+/// ```rust
+/// use arinc429::{decode_param, encode_param, ArincError, Parameter, Ssm};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct HydraulicPressure(u32); // psi
+///
+/// impl Parameter for HydraulicPressure {
+///     fn label() -> u8 {
+///         0o270
+///     }
+///     fn to_data(&self) -> Result<u32, ArincError> {
+///         Ok(self.0 / 4) // 4 psi per LSB
+///     }
+///     fn from_data(data: u32) -> Self {
+///         HydraulicPressure(data * 4)
+///     }
+/// }
+///
+/// let word = encode_param(&HydraulicPressure(3_000), 0, Ssm::NormalOperation).unwrap();
+/// assert_eq!(decode_param::<HydraulicPressure>(word), Ok(HydraulicPressure(3_000)));
+/// ```
+pub trait Parameter: Sized {
+    /// Raw label code the parameter is sent on.
+    fn label() -> u8;
+
+    /// Data field for this value.
+    ///
+    /// # Errors
+    /// Typically [`ArincError::ValueOutOfRange`] or [`ArincError::DataOverflow`] if the
+    /// value cannot be represented.
+    fn to_data(&self) -> Result<u32, ArincError>;
+
+    /// Value carried by a 19-bit data field.
+    fn from_data(data: u32) -> Self;
+}
+
+/// Encode `param` into a word on its label.
+///
+/// # Errors
+/// Any error from [`Parameter::to_data`], or from [`encode`] for an invalid SDI or a data
+/// field wider than 19 bits.
+pub fn encode_param<P: Parameter>(param: &P, sdi: u8, ssm: Ssm) -> Result<u32, ArincError> {
+    encode(P::label(), sdi, param.to_data()?, ssm.as_u8())
+}
+
+/// Decode a word as parameter `P`.
+///
+/// The SSM is not checked; [`decode`] the word as well if its status matters.
+///
+/// # Errors
+/// [`ArincError::ParityMismatch`] for a corrupt word, or [`ArincError::LabelMismatch`]
+/// if the word is not on `P`'s label.
+pub fn decode_param<P: Parameter>(word: u32) -> Result<P, ArincError> {
+    let decoded = decode(word)?;
+    let found = decoded.label.raw();
+    if found != P::label() {
+        return Err(ArincError::LabelMismatch {
+            expected: P::label(),
+            found,
+        });
+    }
+    Ok(P::from_data(decoded.data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{from_twos_complement_19, to_twos_complement_19};
+
+    /// Outside air temperature in tenths of a degree, on a private label.
+    #[derive(Debug, PartialEq)]
+    struct Oat(i32);
+
+    impl Parameter for Oat {
+        fn label() -> u8 {
+            0o233
+        }
+
+        fn to_data(&self) -> Result<u32, ArincError> {
+            to_twos_complement_19(self.0)
+        }
+
+        fn from_data(data: u32) -> Self {
+            Oat(from_twos_complement_19(data))
+        }
+    }
+
+    #[test]
+    fn test_param_roundtrip() {
+        let word = encode_param(&Oat(-563), 2, Ssm::NormalOperation).unwrap();
+        assert_eq!(decode(word).unwrap().sdi, 2);
+        assert_eq!(decode_param::<Oat>(word), Ok(Oat(-563)));
+        assert_eq!(
+            encode_param(&Oat(1 << 20), 0, Ssm::NormalOperation),
+            Err(ArincError::DataOverflow(1 << 20))
+        );
+    }
+
+    #[test]
+    fn test_decode_param_wrong_label() {
+        let word = encode(0o203, 0, 100, 3).unwrap();
+        assert_eq!(
+            decode_param::<Oat>(word),
+            Err(ArincError::LabelMismatch {
+                expected: 0o233,
+                found: 0o203
+            })
+        );
+        assert_eq!(
+            decode_param::<Oat>(word ^ (1 << 31)),
+            Err(ArincError::ParityMismatch)
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! Standard data patterns for exercising a receiver.
+
+use alloc::vec::Vec;
+
+use crate::{encode_raw, ArincWord, Ssm};
+
+const DATA_BITS: u32 = 19;
+const DATA_MASK: u32 = 0x7FFFF;
+
+/// Data-field pattern produced by [`test_pattern`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TestPattern {
+    /// One word with every data bit clear
+    AllZeros,
+    /// One word with every data bit set
+    AllOnes,
+    /// 19 words, each with a single data bit set, from bit 11 up to bit 29
+    Walking1,
+    /// 19 words, each with a single data bit clear, from bit 11 up to bit 29
+    Walking0,
+    /// Every data value from `0` to `0x7FFFF` in order (524,288 words)
+    Ramp,
+}
+
+/// Words carrying `pattern` in the data field of `label`.
+///
+/// Words are sent with SDI 0 and the Functional Test SSM, so a receiver that honours
+/// the SSM will not treat them as live data, and with correct odd parity.
+///
+/// ```rust
+/// use arinc429::{decode, test_pattern, Ssm, TestPattern};
+///
+/// let words = test_pattern(0o012, TestPattern::Walking1);
+/// assert_eq!(words.len(), 19);
+/// let last = decode(words[18]).unwrap();
+/// assert_eq!((last.data, last.ssm), (1 << 18, Ssm::FunctionalTest));
+/// ```
+pub fn test_pattern(label: u8, pattern: TestPattern) -> Vec<u32> {
+    let word = |data| encode_raw(label, 0, data, Ssm::FunctionalTest.as_u8());
+    match pattern {
+        TestPattern::AllZeros => Vec::from([word(0)]),
+        TestPattern::AllOnes => Vec::from([word(DATA_MASK)]),
+        TestPattern::Walking1 => (0..DATA_BITS).map(|bit| word(1 << bit)).collect(),
+        TestPattern::Walking0 => (0..DATA_BITS)
+            .map(|bit| word(!(1 << bit) & DATA_MASK))
+            .collect(),
+        TestPattern::Ramp => (0..=DATA_MASK).map(word).collect(),
+    }
+}
+
+/// Whether `words` show equipment in built-in test cycling a ramp: at least two words,
+/// all on the same label and SDI with the Functional Test SSM, whose data field strictly
+/// increases from each word to the next.
+///
+/// Pass a window of consecutive words from one label, e.g. the tail of a capture. A ramp
+/// that wraps from `0x7FFFF` back to `0` inside the window is not recognised.
+///
+/// ```rust
+/// use arinc429::{decode, is_bite_ramp, test_pattern, TestPattern};
+///
+/// let words: Vec<_> = test_pattern(0o012, TestPattern::Ramp)[100..110]
+///     .iter()
+///     .map(|&w| decode(w).unwrap())
+///     .collect();
+/// assert!(is_bite_ramp(&words));
+/// ```
+pub fn is_bite_ramp(words: &[ArincWord]) -> bool {
+    let [first, ..] = words else {
+        return false;
+    };
+    words.len() >= 2
+        && words
+            .iter()
+            .all(|w| w.ssm == Ssm::FunctionalTest && w.label == first.label && w.sdi == first.sdi)
+        && words.windows(2).all(|pair| pair[1].data > pair[0].data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, Label};
+
+    fn data(words: &[u32]) -> Vec<u32> {
+        words.iter().map(|&w| decode(w).unwrap().data).collect()
+    }
+
+    #[test]
+    fn test_walking1_one_bit_per_word() {
+        let words = test_pattern(0o203, TestPattern::Walking1);
+        assert_eq!(words.len(), 19);
+        for (bit, value) in data(&words).into_iter().enumerate() {
+            assert_eq!(value.count_ones(), 1);
+            assert_eq!(value, 1 << bit);
+        }
+        for word in words {
+            let decoded = decode(word).unwrap();
+            assert_eq!(decoded.label, Label::PressureAltitude);
+            assert_eq!((decoded.sdi, decoded.ssm), (0, Ssm::FunctionalTest));
+        }
+    }
+
+    #[test]
+    fn test_walking0_and_constant_patterns() {
+        for (bit, value) in data(&test_pattern(0o012, TestPattern::Walking0))
+            .into_iter()
+            .enumerate()
+        {
+            assert_eq!(value, DATA_MASK ^ (1 << bit));
+        }
+        assert_eq!(data(&test_pattern(0o012, TestPattern::AllZeros)), [0]);
+        assert_eq!(
+            data(&test_pattern(0o012, TestPattern::AllOnes)),
+            [DATA_MASK]
+        );
+    }
+
+    #[test]
+    fn test_bite_ramp_detection() {
+        let decoded = |words: &[u32]| -> Vec<ArincWord> {
+            words.iter().map(|&w| decode(w).unwrap()).collect()
+        };
+        let raw = test_pattern(0o012, TestPattern::Ramp);
+        assert!(is_bite_ramp(&decoded(&raw[..16])));
+        // Skipped values still count as increasing.
+        let sparse: Vec<_> = raw[..16].iter().step_by(3).copied().collect();
+        assert!(is_bite_ramp(&decoded(&sparse)));
+
+        // Walking ones doubles each time, walking zeros does not increase.
+        assert!(is_bite_ramp(&decoded(&test_pattern(
+            0o012,
+            TestPattern::Walking1
+        ))));
+        assert!(!is_bite_ramp(&decoded(&test_pattern(
+            0o012,
+            TestPattern::Walking0
+        ))));
+
+        let mut ramp = decoded(&raw[..16]);
+        ramp[5].ssm = Ssm::NormalOperation;
+        assert!(!is_bite_ramp(&ramp));
+        ramp[5].ssm = Ssm::FunctionalTest;
+        ramp[5].sdi = 1;
+        assert!(!is_bite_ramp(&ramp));
+        ramp[5].sdi = 0;
+        ramp[5].data = ramp[4].data;
+        assert!(!is_bite_ramp(&ramp));
+        assert!(!is_bite_ramp(&ramp[..1]));
+        assert!(!is_bite_ramp(&[]));
+    }
+
+    #[test]
+    fn test_ramp_covers_every_value() {
+        let values = data(&test_pattern(0o012, TestPattern::Ramp));
+        assert_eq!(values.len(), 1 << 19);
+        assert!(values.iter().enumerate().all(|(i, &v)| v == i as u32));
+    }
+}
@@ -0,0 +1,212 @@
+//! Present-position latitude/longitude split across coarse and fine BNR words.
+//!
+//! A single 19-bit field cannot hold a full-precision position, so ARINC 429 sends each
+//! axis as a signed coarse word (labels 310/311, [`POS_COARSE_RESOLUTION`] per LSB) and an
+//! unsigned fine word (labels 312/313) holding the remainder within one coarse LSB.
+
+use crate::{ArincWord, Label};
+
+/// Weight of one coarse position LSB, in degrees.
+pub const POS_COARSE_RESOLUTION: f64 = 0.04;
+
+/// Weight of one fine position LSB, in degrees (one coarse LSB split over 19 bits).
+pub const POS_FINE_RESOLUTION: f64 = POS_COARSE_RESOLUTION / 524288.0;
+
+/// Combine a coarse and a fine position word into degrees.
+///
+/// The coarse word must carry the position rounded down (toward negative infinity) to a
+/// multiple of [`POS_COARSE_RESOLUTION`], with the fine word holding the non-negative
+/// remainder.
+///
+/// Returns `None` if the labels are not a matching latitude or longitude coarse/fine pair,
+/// the SDIs differ, or either word is not in Normal Operation.
+pub fn combine_position(coarse: &ArincWord, fine: &ArincWord) -> Option<f64> {
+    let paired = matches!(
+        (coarse.label, fine.label),
+        (Label::LatitudeCoarse, Label::LatitudeFine)
+            | (Label::LongitudeCoarse, Label::LongitudeFine)
+    );
+    if !paired || coarse.sdi != fine.sdi {
+        return None;
+    }
+
+    Some(coarse.to_physical()? + fine.to_physical()?)
+}
+
+/// Pair up coarse and fine position words from a live stream into `(lat, lon)` degrees.
+///
+/// The latest coarse and fine word for each axis are buffered, in whichever order they
+/// arrive. Once an axis has both and they combine (see [`combine_position`]), the two
+/// words are used up and the axis value is held until the other axis has a value with
+/// the same SDI; the pair is then emitted and both axes start afresh. Other labels are
+/// ignored.
+///
+/// A stale value is replaced, never reused, so every emitted position comes from four
+/// words not used for an earlier one. Interleave one source only: filter on SDI first
+/// when several systems share the bus.
+pub fn position_pairs<I: Iterator<Item = ArincWord>>(iter: I) -> impl Iterator<Item = (f64, f64)> {
+    PositionPairs {
+        iter,
+        axes: [Axis::default(), Axis::default()],
+    }
+}
+
+#[derive(Default)]
+struct Axis {
+    coarse: Option<ArincWord>,
+    fine: Option<ArincWord>,
+    /// Combined value and its SDI
+    value: Option<(f64, u8)>,
+}
+
+impl Axis {
+    fn try_combine(&mut self) {
+        if let (Some(coarse), Some(fine)) = (&self.coarse, &self.fine) {
+            if let Some(degrees) = combine_position(coarse, fine) {
+                self.value = Some((degrees, coarse.sdi));
+                self.coarse = None;
+                self.fine = None;
+            }
+        }
+    }
+}
+
+struct PositionPairs<I> {
+    iter: I,
+    /// Latitude, then longitude
+    axes: [Axis; 2],
+}
+
+impl<I: Iterator<Item = ArincWord>> Iterator for PositionPairs<I> {
+    type Item = (f64, f64);
+
+    fn next(&mut self) -> Option<(f64, f64)> {
+        for word in self.iter.by_ref() {
+            let (axis, is_coarse) = match word.label {
+                Label::LatitudeCoarse => (0, true),
+                Label::LatitudeFine => (0, false),
+                Label::LongitudeCoarse => (1, true),
+                Label::LongitudeFine => (1, false),
+                _ => continue,
+            };
+            let slot = &mut self.axes[axis];
+            if is_coarse {
+                slot.coarse = Some(word);
+            } else {
+                slot.fine = Some(word);
+            }
+            slot.try_combine();
+
+            let [lat, lon] = &self.axes;
+            if let (Some((lat, lat_sdi)), Some((lon, lon_sdi))) = (lat.value, lon.value) {
+                if lat_sdi == lon_sdi {
+                    self.axes[0].value = None;
+                    self.axes[1].value = None;
+                    return Some((lat, lon));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, encode};
+
+    /// Split `degrees` into (coarse, fine) data fields.
+    fn split(degrees: f64) -> (u32, u32) {
+        let coarse = (degrees / POS_COARSE_RESOLUTION).floor();
+        let fine = ((degrees - coarse * POS_COARSE_RESOLUTION) / POS_FINE_RESOLUTION).round();
+        ((coarse as i32 as u32) & 0x7FFFF, fine as u32)
+    }
+
+    #[test]
+    fn test_combine_known_position() {
+        // Sydney Kingsford Smith: 33.9461° S, 151.1772° E
+        for (degrees, coarse_label, fine_label) in [
+            (-33.9461, Label::LatitudeCoarse, Label::LatitudeFine),
+            (151.1772, Label::LongitudeCoarse, Label::LongitudeFine),
+        ] {
+            let (coarse_data, fine_data) = split(degrees);
+            let coarse = decode(encode(coarse_label.raw(), 1, coarse_data, 3).unwrap()).unwrap();
+            let fine = decode(encode(fine_label.raw(), 1, fine_data, 3).unwrap()).unwrap();
+
+            let combined = combine_position(&coarse, &fine).unwrap();
+            assert!((combined - degrees).abs() <= POS_FINE_RESOLUTION);
+        }
+    }
+
+    fn word(label: Label, sdi: u8, data: u32) -> ArincWord {
+        decode(encode(label.raw(), sdi, data, 3).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_position_pairs_interleaved() {
+        let positions = [(-33.9461, 151.1772), (51.4700, -0.4543)];
+        let mut stream = Vec::new();
+        for (i, &(lat, lon)) in positions.iter().enumerate() {
+            let (lat_coarse, lat_fine) = split(lat);
+            let (lon_coarse, lon_fine) = split(lon);
+            stream.push(word(Label::GroundSpeed, 0, 2000));
+            if i == 0 {
+                stream.push(word(Label::LatitudeCoarse, 0, lat_coarse));
+                stream.push(word(Label::LongitudeCoarse, 0, lon_coarse));
+                stream.push(word(Label::LatitudeFine, 0, lat_fine));
+                stream.push(word(Label::LongitudeFine, 0, lon_fine));
+            } else {
+                // Fine words ahead of their coarse partners.
+                stream.push(word(Label::LongitudeFine, 0, lon_fine));
+                stream.push(word(Label::LatitudeFine, 0, lat_fine));
+                stream.push(word(Label::PressureAltitude, 0, 100));
+                stream.push(word(Label::LatitudeCoarse, 0, lat_coarse));
+                stream.push(word(Label::LongitudeCoarse, 0, lon_coarse));
+            }
+        }
+
+        let pairs: Vec<_> = position_pairs(stream.into_iter()).collect();
+        assert_eq!(pairs.len(), 2);
+        for (&(lat, lon), &(want_lat, want_lon)) in pairs.iter().zip(&positions) {
+            assert!((lat - want_lat).abs() <= POS_FINE_RESOLUTION);
+            assert!((lon - want_lon).abs() <= POS_FINE_RESOLUTION);
+        }
+    }
+
+    #[test]
+    fn test_position_pairs_needs_both_axes_from_one_source() {
+        let (lat_coarse, lat_fine) = split(10.0);
+        let (lon_coarse, lon_fine) = split(20.0);
+        let stream = [
+            word(Label::LatitudeCoarse, 1, lat_coarse),
+            word(Label::LatitudeFine, 1, lat_fine),
+            word(Label::LongitudeCoarse, 2, lon_coarse),
+            word(Label::LongitudeFine, 2, lon_fine),
+            // A lone latitude pair never completes a position.
+            word(Label::LatitudeCoarse, 1, lat_coarse),
+            word(Label::LatitudeFine, 1, lat_fine),
+        ];
+        assert_eq!(position_pairs(stream.into_iter()).count(), 0);
+
+        let stream = [
+            word(Label::LongitudeCoarse, 2, lon_coarse),
+            word(Label::LongitudeFine, 2, lon_fine),
+            word(Label::LatitudeFine, 2, lat_fine),
+            word(Label::LatitudeCoarse, 2, lat_coarse),
+        ];
+        let (lat, lon) = position_pairs(stream.into_iter()).next().unwrap();
+        assert!((lat - 10.0).abs() <= POS_FINE_RESOLUTION);
+        assert!((lon - 20.0).abs() <= POS_FINE_RESOLUTION);
+    }
+
+    #[test]
+    fn test_combine_rejects_mismatch() {
+        let lat_coarse = decode(encode(Label::LatitudeCoarse.raw(), 0, 100, 3).unwrap()).unwrap();
+        let lon_fine = decode(encode(Label::LongitudeFine.raw(), 0, 100, 3).unwrap()).unwrap();
+        let lat_fine_sdi1 = decode(encode(Label::LatitudeFine.raw(), 1, 100, 3).unwrap()).unwrap();
+
+        assert_eq!(combine_position(&lat_coarse, &lon_fine), None);
+        assert_eq!(combine_position(&lat_coarse, &lat_fine_sdi1), None);
+        assert_eq!(combine_position(&lon_fine, &lat_coarse), None);
+    }
+}
@@ -0,0 +1,128 @@
+//! Live update-rate measurement per label.
+
+use alloc::collections::{BTreeMap, VecDeque};
+use core::time::Duration;
+
+use crate::Label;
+
+/// Number of timestamps kept per label by [`RateMonitor::new`].
+const DEFAULT_WINDOW: usize = 8;
+
+/// Measures how often each label is received, over a sliding window of its most recent
+/// timestamps.
+///
+/// Timestamps are any monotonic clock expressed as a [`Duration`], as for
+/// [`FreshnessTracker`](crate::FreshnessTracker). Words from every SDI count towards
+/// their label's rate.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use arinc429::RateMonitor;
+///
+/// let mut monitor = RateMonitor::new();
+/// for i in 0..10 {
+///     monitor.record(0o203, Duration::from_millis(i * 50)); // pressure altitude at 20 Hz
+/// }
+/// assert_eq!(monitor.rate_hz(0o203), Some(20.0));
+/// assert_eq!(monitor.is_within_spec(0o203), Some(true));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateMonitor {
+    window: usize,
+    timestamps: BTreeMap<u8, VecDeque<Duration>>,
+}
+
+impl Default for RateMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RateMonitor {
+    /// Create a monitor averaging over the last 8 words of each label.
+    pub fn new() -> Self {
+        Self::with_window(DEFAULT_WINDOW)
+    }
+
+    /// Create a monitor averaging over the last `window` words of each label.
+    ///
+    /// # Panics
+    /// If `window` is less than 2, since a rate needs at least two words.
+    pub fn with_window(window: usize) -> Self {
+        assert!(window >= 2, "rate window must hold at least 2 timestamps");
+        Self {
+            window,
+            timestamps: BTreeMap::new(),
+        }
+    }
+
+    /// Record that a word with raw label code `label` was received at `timestamp`.
+    pub fn record(&mut self, label: u8, timestamp: Duration) {
+        let recent = self.timestamps.entry(label).or_default();
+        if recent.len() == self.window {
+            recent.pop_front();
+        }
+        recent.push_back(timestamp);
+    }
+
+    /// Average update rate of `label` over the window, in Hz.
+    ///
+    /// Returns `None` until two words with distinct timestamps have been recorded.
+    pub fn rate_hz(&self, label: u8) -> Option<f64> {
+        let recent = self.timestamps.get(&label)?;
+        let span = recent.back()?.checked_sub(*recent.front()?)?;
+        if span.is_zero() {
+            return None;
+        }
+        Some((recent.len() - 1) as f64 / span.as_secs_f64())
+    }
+
+    /// Whether the measured rate of `label` lies within its standard
+    /// [`transmission_interval`](Label::transmission_interval).
+    ///
+    /// Returns `None` if there is no rate yet or the label has no standard interval.
+    pub fn is_within_spec(&self, label: u8) -> Option<bool> {
+        let (min, max) = Label::from_u8(label).transmission_interval()?;
+        let rate = self.rate_hz(label)?;
+        Some((1.0 / max.as_secs_f64()..=1.0 / min.as_secs_f64()).contains(&rate))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_periodic_rate() {
+        let mut monitor = RateMonitor::with_window(4);
+        assert_eq!(monitor.rate_hz(0o012), None);
+        monitor.record(0o012, ms(0));
+        assert_eq!(monitor.rate_hz(0o012), None);
+
+        for i in 1..20 {
+            monitor.record(0o012, ms(i * 40));
+        }
+        assert!((monitor.rate_hz(0o012).unwrap() - 25.0).abs() < 1e-9);
+        assert_eq!(monitor.is_within_spec(0o012), Some(true));
+
+        // The window forgets the old rate once the label slows to 10 Hz.
+        for i in 1..=3 {
+            monitor.record(0o012, ms(760 + i * 100));
+        }
+        assert!((monitor.rate_hz(0o012).unwrap() - 10.0).abs() < 1e-9);
+        assert_eq!(monitor.is_within_spec(0o012), Some(false));
+    }
+
+    #[test]
+    fn test_unknown_label_has_no_spec() {
+        let mut monitor = RateMonitor::new();
+        monitor.record(0o100, ms(0));
+        monitor.record(0o100, ms(10));
+        assert_eq!(monitor.rate_hz(0o100), Some(100.0));
+        assert_eq!(monitor.is_within_spec(0o100), None);
+    }
+}
@@ -0,0 +1,205 @@
+//! Streaming decoder for words read from any [`std::io::Read`] source.
+
+use std::io::{self, Read};
+
+use crate::{decode, peek_label, ArincError, ArincWord, Endian};
+
+/// Iterator decoding 32-bit ARINC 429 words from a byte stream.
+///
+/// Each item is an I/O result wrapping the decode result, so stream errors and protocol
+/// errors stay distinguishable. Iteration ends cleanly at EOF on a word boundary; EOF in
+/// the middle of a word yields [`io::ErrorKind::UnexpectedEof`].
+///
+/// ```rust
+/// use arinc429::{encode, WordReader};
+///
+/// let bytes = encode(10, 0, 2000, 3).unwrap().to_le_bytes();
+/// let mut reader = WordReader::new(&bytes[..]);
+/// let word = reader.next().unwrap().unwrap().unwrap();
+/// assert_eq!(word.to_physical(), Some(250.0));
+/// assert!(reader.next().is_none());
+/// ```
+#[derive(Debug)]
+pub struct WordReader<R> {
+    reader: R,
+    endian: Endian,
+    /// Bitset of raw label codes to yield, or `None` for all labels
+    filter: Option<[u64; 4]>,
+}
+
+impl<R: Read> WordReader<R> {
+    /// Create a reader for little-endian words.
+    pub fn new(reader: R) -> Self {
+        Self::with_endian(reader, Endian::Little)
+    }
+
+    /// Create a reader for words in the given byte order.
+    pub fn with_endian(reader: R, endian: Endian) -> Self {
+        Self {
+            reader,
+            endian,
+            filter: None,
+        }
+    }
+
+    /// Only yield words whose raw label code (e.g. `0o203`) is in `labels`.
+    ///
+    /// Other words are skipped after reading just the label byte: they are not
+    /// parity-checked or decoded, so a corrupt word on an unwanted label produces no
+    /// error. Replaces any previous filter; an empty slice skips every word.
+    ///
+    /// ```rust
+    /// use arinc429::{encode, Label, WordReader};
+    ///
+    /// let bytes: Vec<u8> = [encode(0o012, 0, 2000, 3), encode(0o203, 0, 1000, 3)]
+    ///     .iter()
+    ///     .flat_map(|w| w.as_ref().unwrap().to_le_bytes())
+    ///     .collect();
+    /// let mut reader = WordReader::new(&bytes[..]);
+    /// reader.filter_labels(&[0o203]);
+    ///
+    /// let labels: Vec<_> = reader.map(|r| r.unwrap().unwrap().label).collect();
+    /// assert_eq!(labels, [Label::PressureAltitude]);
+    /// ```
+    pub fn filter_labels(&mut self, labels: &[u8]) {
+        let mut set = [0u64; 4];
+        for &code in labels {
+            set[usize::from(code / 64)] |= 1 << (code % 64);
+        }
+        self.filter = Some(set);
+    }
+
+    /// Stop filtering and yield every word again.
+    pub fn clear_filter(&mut self) {
+        self.filter = None;
+    }
+
+    /// Whether a raw word passes the label filter, judged from bits 1–8 alone.
+    fn wanted(&self, word: u32) -> bool {
+        self.filter.is_none_or(|set| {
+            let code = peek_label(word);
+            set[usize::from(code / 64)] & (1 << (code % 64)) != 0
+        })
+    }
+
+    /// Consume the `WordReader`, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Read the next raw 32-bit word, or `None` at EOF on a word boundary.
+    fn read_word(&mut self) -> io::Result<Option<u32>> {
+        let mut buf = [0u8; 4];
+        let mut filled = 0;
+
+        while filled < buf.len() {
+            match self.reader.read(&mut buf[filled..]) {
+                Ok(0) if filled == 0 => return Ok(None),
+                Ok(0) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        format!("stream ended after {} bytes of a word", filled),
+                    ))
+                }
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(Some(self.endian.word_from_bytes(buf)))
+    }
+}
+
+impl<R: Read> Iterator for WordReader<R> {
+    type Item = io::Result<Result<ArincWord, ArincError>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.read_word() {
+                Ok(Some(word)) if !self.wanted(word) => continue,
+                Ok(Some(word)) => return Some(Ok(decode(word))),
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{encode, Label};
+
+    /// Reader that hands out at most one byte per call to exercise partial reads.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match (self.0.split_first(), buf.first_mut()) {
+                (Some((byte, rest)), Some(slot)) => {
+                    *slot = *byte;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn test_partial_reads_big_endian() {
+        let words = [
+            encode(10, 0, 2000, 3).unwrap(),
+            encode(131, 0, 25000, 3).unwrap(),
+        ];
+        let bytes: Vec<u8> = words.iter().flat_map(|w| w.to_be_bytes()).collect();
+
+        let decoded: Vec<_> = WordReader::with_endian(Trickle(&bytes), Endian::Big)
+            .map(|r| r.unwrap().unwrap())
+            .collect();
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0].label, Label::GroundSpeed);
+        assert_eq!(decoded[1].to_physical(), Some(25000.0));
+    }
+
+    #[test]
+    fn test_truncated_stream() {
+        let bytes = [0x50, 0x40, 0x1F, 0xE0, 0x01, 0x02];
+        let mut reader = WordReader::new(&bytes[..]);
+        assert_eq!(reader.next().unwrap().unwrap().unwrap().data, 2000);
+
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_filter_skips_without_decoding() {
+        // 10 000 words, mostly unwanted labels with corrupt parity. If skipped words were
+        // decoded they would surface as ParityMismatch errors.
+        let wanted = encode(0o203, 0, 1000, 3).unwrap();
+        let corrupt = encode(0o012, 0, 2000, 3).unwrap() ^ (1 << 31);
+        let bytes: Vec<u8> = (0..10_000)
+            .map(|i| if i % 100 == 0 { wanted } else { corrupt })
+            .flat_map(|w| w.to_le_bytes())
+            .collect();
+
+        let mut reader = WordReader::new(&bytes[..]);
+        reader.filter_labels(&[0o203, 0o377]);
+        let decoded: Vec<_> = reader.map(|r| r.unwrap().unwrap()).collect();
+        assert_eq!(decoded.len(), 100);
+        assert!(decoded.iter().all(|w| w.label == Label::PressureAltitude));
+    }
+
+    #[test]
+    fn test_clear_filter() {
+        let bytes: Vec<u8> = [encode(0o012, 0, 2000, 3), encode(0o203, 0, 1000, 3)]
+            .iter()
+            .flat_map(|w| w.as_ref().unwrap().to_le_bytes())
+            .collect();
+        let mut reader = WordReader::new(&bytes[..]);
+        reader.filter_labels(&[]);
+        reader.clear_filter();
+        assert_eq!(reader.count(), 2);
+    }
+}
@@ -0,0 +1,231 @@
+//! Periodic word generation for feeding a simulated bus from a simulation loop.
+//!
+//! Register each label with a closure producing its current physical value, then call
+//! [`WordScheduler::tick`] once per frame with the simulation time. Every label whose
+//! transmit interval has elapsed is sampled, encoded and returned, so each parameter
+//! goes out at its own rate regardless of the frame rate.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+use core::time::Duration;
+
+use crate::{encode, encode_physical, ArincError, Label, Ssm};
+
+struct Entry {
+    label: Label,
+    sdi: u8,
+    interval: Duration,
+    next_due: Duration,
+    source: Box<dyn FnMut() -> f64>,
+}
+
+/// Emits encoded words for a set of labels, each at its own transmit interval.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use arinc429::{decode, sim::WordScheduler, Label};
+///
+/// let mut scheduler = WordScheduler::new();
+/// scheduler.add(Label::GroundSpeed, 0, || 250.0).unwrap();
+///
+/// // Ground speed repeats every 50 ms.
+/// let words = scheduler.tick(Duration::ZERO);
+/// assert_eq!(decode(words[0]).unwrap().to_physical(), Some(250.0));
+/// assert!(scheduler.tick(Duration::from_millis(20)).is_empty());
+/// assert_eq!(scheduler.tick(Duration::from_millis(50)).len(), 1);
+/// ```
+#[derive(Default)]
+pub struct WordScheduler {
+    entries: Vec<Entry>,
+}
+
+impl WordScheduler {
+    /// Create an empty scheduler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Schedule `label` at its standard rate, the maximum of
+    /// [`Label::transmission_interval`].
+    ///
+    /// # Errors
+    /// - [`ArincError::UnsupportedLabel`] if the label has no BNR encoding or no
+    ///   standard interval
+    /// - [`ArincError::InvalidSdi`] if `sdi` is greater than 3
+    pub fn add(
+        &mut self,
+        label: Label,
+        sdi: u8,
+        source: impl FnMut() -> f64 + 'static,
+    ) -> Result<(), ArincError> {
+        let (_, max) = label
+            .transmission_interval()
+            .ok_or(ArincError::UnsupportedLabel(label))?;
+        self.add_with_interval(label, sdi, max, source)
+    }
+
+    /// Schedule `label` every `interval`.
+    ///
+    /// The first word goes out on the first [`tick`](Self::tick).
+    ///
+    /// # Errors
+    /// As for [`add`](Self::add), except that no standard interval is required.
+    ///
+    /// # Panics
+    /// If `interval` is zero.
+    pub fn add_with_interval(
+        &mut self,
+        label: Label,
+        sdi: u8,
+        interval: Duration,
+        source: impl FnMut() -> f64 + 'static,
+    ) -> Result<(), ArincError> {
+        assert!(!interval.is_zero(), "transmit interval must be non-zero");
+        if label.resolution().is_none() {
+            return Err(ArincError::UnsupportedLabel(label));
+        }
+        if sdi > 3 {
+            return Err(ArincError::InvalidSdi(sdi));
+        }
+        self.entries.push(Entry {
+            label,
+            sdi,
+            interval,
+            next_due: Duration::ZERO,
+            source: Box::new(source),
+        });
+        Ok(())
+    }
+
+    /// Number of scheduled labels.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no labels are scheduled.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Return the words due at simulation time `now`, in registration order.
+    ///
+    /// Each due label's source is sampled once. A value that cannot be encoded (out of
+    /// the label's range) is sent as a No Computed Data word with a zero data field. If
+    /// `now` has jumped past several intervals the label is sent once, not once per
+    /// missed interval.
+    pub fn tick(&mut self, now: Duration) -> Vec<u32> {
+        let mut words = Vec::new();
+        for entry in &mut self.entries {
+            if now < entry.next_due {
+                continue;
+            }
+
+            let value = (entry.source)();
+            let word = encode_physical(entry.label, value, entry.sdi, Ssm::NormalOperation)
+                .or_else(|_| encode(entry.label.raw(), entry.sdi, 0, Ssm::NoComputedData.as_u8()))
+                .expect("SDI validated when the label was added");
+            words.push(word);
+
+            entry.next_due += entry.interval;
+            if entry.next_due <= now {
+                entry.next_due = now + entry.interval;
+            }
+        }
+        words
+    }
+}
+
+impl fmt::Debug for WordScheduler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.entries.iter().map(|e| (e.label, e.sdi, e.interval)))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_labels_run_at_their_own_rates() {
+        let mut scheduler = WordScheduler::new();
+        scheduler.add(Label::RollAngle, 0, || 1.0).unwrap();
+        scheduler.add(Label::Tat, 0, || 15.0).unwrap();
+
+        let mut counts = [0usize; 2];
+        for frame in 0..100 {
+            for word in scheduler.tick(ms(frame * 10)) {
+                match decode(word).unwrap().label {
+                    Label::RollAngle => counts[0] += 1,
+                    Label::Tat => counts[1] += 1,
+                    other => panic!("unexpected label {:?}", other),
+                }
+            }
+        }
+        // One second of frames: roll every 50 ms, TAT every 500 ms.
+        assert_eq!(counts, [20, 2]);
+    }
+
+    #[test]
+    fn test_source_sampled_when_due() {
+        let mut scheduler = WordScheduler::new();
+        let mut altitude = 0.0;
+        scheduler
+            .add_with_interval(Label::PressureAltitude, 1, ms(100), move || {
+                altitude += 100.0;
+                altitude
+            })
+            .unwrap();
+
+        let first = decode(scheduler.tick(ms(0))[0]).unwrap();
+        assert_eq!((first.sdi, first.to_physical()), (1, Some(100.0)));
+        assert!(scheduler.tick(ms(99)).is_empty());
+        let second = decode(scheduler.tick(ms(100))[0]).unwrap();
+        assert_eq!(second.to_physical(), Some(200.0));
+    }
+
+    #[test]
+    fn test_catch_up_sends_once() {
+        let mut scheduler = WordScheduler::new();
+        scheduler
+            .add_with_interval(Label::Mach, 0, ms(10), || 0.8)
+            .unwrap();
+        assert_eq!(scheduler.tick(ms(0)).len(), 1);
+        assert_eq!(scheduler.tick(ms(1000)).len(), 1);
+        assert!(scheduler.tick(ms(1005)).is_empty());
+        assert_eq!(scheduler.tick(ms(1010)).len(), 1);
+    }
+
+    #[test]
+    fn test_unencodable_value_sends_ncd() {
+        let mut scheduler = WordScheduler::new();
+        scheduler.add(Label::GroundSpeed, 0, || -1.0).unwrap();
+        let word = decode(scheduler.tick(ms(0))[0]).unwrap();
+        assert_eq!((word.ssm, word.data), (Ssm::NoComputedData, 0));
+    }
+
+    #[test]
+    fn test_add_rejects_bcd_and_unknown_labels() {
+        let mut scheduler = WordScheduler::new();
+        assert_eq!(
+            scheduler.add(Label::Date, 0, || 0.0),
+            Err(ArincError::UnsupportedLabel(Label::Date))
+        );
+        assert_eq!(
+            scheduler.add(Label::Unknown(0o377), 0, || 0.0),
+            Err(ArincError::UnsupportedLabel(Label::Unknown(0o377)))
+        );
+        assert_eq!(
+            scheduler.add(Label::GroundSpeed, 4, || 0.0),
+            Err(ArincError::InvalidSdi(4))
+        );
+        assert!(scheduler.is_empty());
+    }
+}
@@ -0,0 +1,105 @@
+//! Running statistics over a stream of BNR words.
+
+use crate::{ArincWord, Ssm};
+
+/// Minimum, maximum and mean physical value of a parameter, accumulated without storing
+/// samples.
+///
+/// Feed it the words of one label (and usually one SDI); words that are not Normal
+/// Operation or have no physical interpretation are ignored.
+///
+/// ```rust
+/// use arinc429::{decode, encode, BnrStats};
+///
+/// let mut stats = BnrStats::new();
+/// for counts in [1600, 2000, 2400] {
+///     stats.update(&decode(encode(0o012, 0, counts, 3).unwrap()).unwrap());
+/// }
+/// assert_eq!(stats.min(), Some(200.0));
+/// assert_eq!(stats.max(), Some(300.0));
+/// assert_eq!(stats.mean(), Some(250.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BnrStats {
+    min: f64,
+    max: f64,
+    sum: f64,
+    count: u64,
+}
+
+impl Default for BnrStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BnrStats {
+    /// Create an accumulator with no samples.
+    pub const fn new() -> Self {
+        Self {
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    /// Add `word`'s physical value, if it is valid.
+    pub fn update(&mut self, word: &ArincWord) {
+        if word.ssm != Ssm::NormalOperation {
+            return;
+        }
+        let Some(value) = word.to_physical() else {
+            return;
+        };
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.sum += value;
+        self.count += 1;
+    }
+
+    /// Number of samples accumulated.
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Smallest value seen, or `None` before the first sample.
+    pub fn min(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    /// Largest value seen, or `None` before the first sample.
+    pub fn max(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.max)
+    }
+
+    /// Arithmetic mean of the values seen, or `None` before the first sample.
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.sum / self.count as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, encode};
+
+    #[test]
+    fn test_ground_speed_ramp() {
+        let mut stats = BnrStats::new();
+        assert_eq!(stats.mean(), None);
+
+        // 100 to 199 knots in 1 knot steps.
+        for knots in 100..200 {
+            stats.update(&decode(encode(0o012, 0, knots * 8, 3).unwrap()).unwrap());
+        }
+        // Invalid status and non-BNR words are skipped.
+        stats.update(&decode(encode(0o012, 0, 8000, 1).unwrap()).unwrap());
+        stats.update(&decode(encode(0o150, 0, 0, 3).unwrap()).unwrap());
+
+        assert_eq!(stats.count(), 100);
+        assert_eq!(stats.min(), Some(100.0));
+        assert_eq!(stats.max(), Some(199.0));
+        assert_eq!(stats.mean(), Some(149.5));
+    }
+}
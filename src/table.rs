@@ -0,0 +1,524 @@
+//! Runtime label definitions for interpreting labels beyond the built-in [`Label`] set.
+
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+
+#[cfg(feature = "alloc")]
+use crate::{decode, unpack_as, ArincError, Label};
+use crate::{field_mask, gray_to_binary, sign_extend, ArincWord, BcdSsm, DataType, Ssm};
+
+/// Interpretation of a single label's data field.
+///
+/// `resolution`, `signed`, `significant_bits`, `sign_in_ssm`, `gray`, `sdi_is_data` and
+/// `heading` only apply when `data_type` is [`DataType::Bnr`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LabelSpec {
+    /// Human-readable parameter name
+    pub name: &'static str,
+    /// Physical units (empty string if none)
+    pub units: &'static str,
+    /// Weight of the least significant data bit in physical units
+    pub resolution: f64,
+    /// Whether the BNR value is two's complement signed
+    pub signed: bool,
+    /// Number of data field bits carrying the value, counted from the LSB (1–19, or 1–21
+    /// with `sdi_is_data`)
+    pub significant_bits: u8,
+    /// Data field encoding
+    pub data_type: DataType,
+    /// Whether the sign is carried in the SSM (`00` plus, `11` minus) instead of the data
+    /// field. The data field then holds the unsigned magnitude and `signed` should be false.
+    pub sign_in_ssm: bool,
+    /// Whether the significant bits are Gray-coded, converted to binary before the sign
+    /// and resolution are applied
+    pub gray: bool,
+    /// Whether ARINC bits 9–10 are the two least significant data bits rather than an SDI,
+    /// widening the data field to 21 bits.
+    ///
+    /// Set for the built-in GNSS latitude/longitude labels (110/111); see
+    /// [`Label::sdi_is_data`](crate::Label::sdi_is_data). Words of other labels defined
+    /// this way must be decoded with [`LabelTable::decode`] to carry the full field.
+    pub sdi_is_data: bool,
+    /// Whether the value is a modular angle, normalized into `[0, 360)` degrees so a
+    /// negative two's complement heading reads as its compass equivalent
+    pub heading: bool,
+}
+
+impl LabelSpec {
+    /// Spec for a BNR label.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `significant_bits` is outside 1–21; in a `const` item this is a compile
+    /// error. Specs with more than 19 bits only scale once
+    /// [`with_sdi_as_data`](Self::with_sdi_as_data) is applied.
+    pub const fn bnr(
+        name: &'static str,
+        units: &'static str,
+        resolution: f64,
+        signed: bool,
+        significant_bits: u8,
+    ) -> Self {
+        assert!(
+            significant_bits >= 1 && significant_bits <= 21,
+            "significant_bits must be 1-21"
+        );
+        Self {
+            name,
+            units,
+            resolution,
+            signed,
+            significant_bits,
+            data_type: DataType::Bnr,
+            sign_in_ssm: false,
+            gray: false,
+            sdi_is_data: false,
+            heading: false,
+        }
+    }
+
+    /// This spec with the sign taken from the SSM (see [`LabelSpec::sign_in_ssm`]).
+    ///
+    /// ```rust
+    /// use arinc429::LabelSpec;
+    ///
+    /// let spec = LabelSpec::bnr("Cross Track Distance", "NM", 0.01, false, 18).with_sign_in_ssm();
+    /// assert!(spec.sign_in_ssm);
+    /// ```
+    pub const fn with_sign_in_ssm(mut self) -> Self {
+        self.sign_in_ssm = true;
+        self
+    }
+
+    /// This spec with a Gray-coded data field (see [`LabelSpec::gray`]).
+    pub const fn with_gray(mut self) -> Self {
+        self.gray = true;
+        self
+    }
+
+    /// This spec with the SDI bits as the low data bits (see [`LabelSpec::sdi_is_data`]).
+    ///
+    /// ```rust
+    /// use arinc429::LabelSpec;
+    ///
+    /// // 20 significant bits spanning ARINC bits 9-28, sign in bit 29.
+    /// let spec = LabelSpec::bnr("Latitude", "°", 180.0 / 1048576.0, true, 21).with_sdi_as_data();
+    /// assert!(spec.sdi_is_data);
+    /// ```
+    pub const fn with_sdi_as_data(mut self) -> Self {
+        self.sdi_is_data = true;
+        self
+    }
+
+    /// This spec with the value wrapped into `[0, 360)` (see [`LabelSpec::heading`]).
+    pub const fn with_heading(mut self) -> Self {
+        self.heading = true;
+        self
+    }
+
+    /// Whether `significant_bits` fits the data field: 1–19, or 1–21 with
+    /// [`sdi_is_data`](Self::sdi_is_data).
+    pub(crate) const fn bits_valid(&self) -> bool {
+        let max = if self.sdi_is_data { 21 } else { 19 };
+        self.significant_bits >= 1 && self.significant_bits <= max
+    }
+
+    /// Scale a raw data field to a physical value, or `None` if the spec is not BNR or
+    /// its `significant_bits` does not fit the data field.
+    pub(crate) fn scale(&self, data: u32) -> Option<f64> {
+        if self.data_type != DataType::Bnr || !self.bits_valid() {
+            return None;
+        }
+
+        let mut field = data & field_mask(self.significant_bits);
+        if self.gray {
+            field = gray_to_binary(field);
+        }
+        let raw = if self.signed {
+            sign_extend(field, self.significant_bits)
+        } else {
+            field as i32
+        };
+
+        Some(raw as f64 * self.resolution)
+    }
+
+    /// Signed physical value of a word, applying the SSM sign when
+    /// [`sign_in_ssm`](Self::sign_in_ssm) is set and wrapping headings into `[0, 360)`.
+    /// The SSM is not checked for validity.
+    pub(crate) fn value(&self, word: &ArincWord) -> Option<f64> {
+        let mut value = self.scale(word.data)?;
+        if self.sign_in_ssm && BcdSsm::from_u8(word.ssm.as_u8()) == BcdSsm::Minus {
+            value = -value;
+        }
+        if self.heading {
+            value %= 360.0;
+            if value < 0.0 {
+                value += 360.0;
+            }
+        }
+        Some(value)
+    }
+
+    /// Whether `ssm` marks valid data for this spec: Normal Operation, or Plus/Minus when
+    /// the sign is carried in the SSM.
+    pub(crate) fn ssm_valid(&self, ssm: Ssm) -> bool {
+        if self.sign_in_ssm {
+            matches!(BcdSsm::from_u8(ssm.as_u8()), BcdSsm::Plus | BcdSsm::Minus)
+        } else {
+            ssm == Ssm::NormalOperation
+        }
+    }
+
+    /// Physical value of a word, or `None` if the SSM does not mark it valid.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn physical(&self, word: &ArincWord) -> Option<f64> {
+        if !self.ssm_valid(word.ssm) {
+            return None;
+        }
+        self.value(word)
+    }
+}
+
+/// ARINC 429 equipment identifier (Attachment 1), e.g. `0x006` for an Air Data Computer.
+///
+/// The same label code can carry different parameters depending on the transmitting
+/// equipment; [`LabelTable`] can hold per-equipment definitions keyed by this ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EquipmentId(pub u16);
+
+impl EquipmentId {
+    /// Inertial Reference System (IRS) – equipment ID 004
+    pub const IRS: EquipmentId = EquipmentId(0x004);
+    /// Digital Air Data Computer (DADC) – equipment ID 006
+    pub const DADC: EquipmentId = EquipmentId(0x006);
+}
+
+/// Registry mapping raw label codes to their [`LabelSpec`].
+///
+/// Use [`LabelTable::builtin`] for the labels known to this crate, then
+/// [`insert`](LabelTable::insert) labels from an ICD. Definitions that only apply to one
+/// piece of equipment go in with [`insert_for`](LabelTable::insert_for) and take precedence
+/// over the generic ones in [`get_for`](LabelTable::get_for).
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LabelTable {
+    specs: BTreeMap<u8, LabelSpec>,
+    equipment: BTreeMap<(EquipmentId, u8), LabelSpec>,
+}
+
+#[cfg(feature = "alloc")]
+impl LabelTable {
+    /// Create an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a table seeded with every built-in [`Label`] and the equipment-specific
+    /// labels of [`EquipmentId::DADC`] and [`EquipmentId::IRS`].
+    pub fn builtin() -> Self {
+        let mut table = Self::new();
+        for label in Label::all() {
+            if let Some(spec) = label.spec() {
+                table.insert(label.raw(), spec);
+            }
+        }
+
+        table.insert_for(
+            EquipmentId::DADC,
+            0o206,
+            LabelSpec::bnr("Computed Airspeed", "knots", 0.0625, false, 19),
+        );
+        if let Some(spec) = Label::VerticalSpeed.spec() {
+            table.insert_for(EquipmentId::DADC, 0o212, spec);
+        }
+        table.insert_for(
+            EquipmentId::IRS,
+            0o212,
+            LabelSpec::bnr("Inertial Vertical Speed", "ft/min", 1.0, true, 19),
+        );
+        table.insert_for(
+            EquipmentId::IRS,
+            0o361,
+            LabelSpec::bnr("Inertial Altitude", "feet", 0.125, true, 19),
+        );
+        table
+    }
+
+    /// Define (or redefine) a label, returning the previous spec if there was one.
+    pub fn insert(&mut self, code: u8, spec: LabelSpec) -> Option<LabelSpec> {
+        self.specs.insert(code, spec)
+    }
+
+    /// Look up the spec for a raw label code.
+    pub fn get(&self, code: u8) -> Option<&LabelSpec> {
+        self.specs.get(&code)
+    }
+
+    /// Define (or redefine) a label for one piece of equipment only.
+    pub fn insert_for(
+        &mut self,
+        equip: EquipmentId,
+        code: u8,
+        spec: LabelSpec,
+    ) -> Option<LabelSpec> {
+        self.equipment.insert((equip, code), spec)
+    }
+
+    /// Look up the spec for a label sent by `equip`, falling back to the generic definition.
+    pub fn get_for(&self, equip: EquipmentId, code: u8) -> Option<&LabelSpec> {
+        self.equipment
+            .get(&(equip, code))
+            .or_else(|| self.get(code))
+    }
+
+    /// Decode `word`, folding bits 9–10 into the data field if the table defines its label
+    /// with [`LabelSpec::sdi_is_data`].
+    ///
+    /// Labels the table does not define are decoded as by [`decode`].
+    ///
+    /// ```rust
+    /// use arinc429::{encode, LabelSpec, LabelTable};
+    ///
+    /// let mut table = LabelTable::new();
+    /// let spec = LabelSpec::bnr("Hybrid Latitude", "°", 180.0 / 1048576.0, true, 21);
+    /// table.insert(0o254, spec.with_sdi_as_data());
+    ///
+    /// let word = table.decode(encode(0o254, 3, 0x10000, 3).unwrap()).unwrap();
+    /// assert_eq!((word.sdi, word.data), (0, 0x40003));
+    /// assert_eq!(word.to_physical_with(&table), Some(0x40003 as f64 * 180.0 / 1048576.0));
+    /// ```
+    ///
+    /// # Errors
+    /// [`ArincError::ParityMismatch`] if the word fails odd parity.
+    pub fn decode(&self, word: u32) -> Result<ArincWord, ArincError> {
+        let decoded = decode(word)?;
+        Ok(match self.get(decoded.label.raw()) {
+            Some(spec) => unpack_as(word, spec.sdi_is_data),
+            None => decoded,
+        })
+    }
+
+    /// Whether an equipment-specific definition exists for this code.
+    fn overrides(&self, equip: EquipmentId, code: u8) -> bool {
+        self.equipment.contains_key(&(equip, code))
+    }
+}
+
+/// Decode a word sent by a known piece of equipment.
+///
+/// Behaves like [`decode`], except that labels with an equipment-specific definition in
+/// `table` are reported as [`Label::Unknown`] so the built-in meaning of the code is not
+/// assumed. Interpret them with [`ArincWord::to_physical_for`]. Bits 9–10 are folded into
+/// the data field when `equip`'s definition has [`LabelSpec::sdi_is_data`], as in
+/// [`LabelTable::decode`].
+#[cfg(feature = "alloc")]
+pub fn decode_for_equipment(
+    word: u32,
+    equip: EquipmentId,
+    table: &LabelTable,
+) -> Result<ArincWord, ArincError> {
+    let mut decoded = decode(word)?;
+    let code = decoded.label.raw();
+    if let Some(spec) = table.get_for(equip, code) {
+        decoded = unpack_as(word, spec.sdi_is_data);
+    }
+    if table.overrides(equip, code) {
+        decoded.label = Label::Unknown(code);
+    }
+    Ok(decoded)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::{decode, encode, Label, Ssm};
+
+    #[test]
+    fn test_builtin_matches_label() {
+        let table = LabelTable::builtin();
+        let spec = table.get(Label::Tat.raw()).unwrap();
+        assert_eq!(spec.name, Label::Tat.name());
+        assert_eq!(spec.resolution, 0.25);
+        assert!(spec.signed);
+        assert_eq!(
+            table.get(Label::Date.raw()).unwrap().data_type,
+            DataType::Bcd
+        );
+        assert!(table.get(0).is_none());
+    }
+
+    #[test]
+    fn test_custom_label() {
+        let mut table = LabelTable::builtin();
+        table.insert(
+            0o270,
+            LabelSpec::bnr("Cabin Pressure", "psi", 0.01, true, 12),
+        );
+
+        // 12-bit field: 0xFFF is -1 count
+        let word = decode(encode(0o270, 0, 0xFFF, 3).unwrap()).unwrap();
+        assert_eq!(word.label, Label::Unknown(0o270));
+        assert_eq!(word.to_physical(), None);
+        assert_eq!(word.to_physical_with(&table), Some(-0.01));
+
+        let word = decode(encode(10, 0, 2000, Ssm::NormalOperation.as_u8()).unwrap()).unwrap();
+        assert_eq!(word.to_physical_with(&table), word.to_physical());
+        assert_eq!(word.to_physical_with(&LabelTable::new()), None);
+    }
+
+    #[test]
+    fn test_equipment_specific_labels() {
+        let table = LabelTable::builtin();
+        let word = encode(0o212, 0, 0x7FFF0, 3).unwrap(); // -16 counts (19-bit)
+
+        let dadc = decode_for_equipment(word, EquipmentId::DADC, &table).unwrap();
+        let irs = decode_for_equipment(word, EquipmentId::IRS, &table).unwrap();
+        assert_eq!(dadc.label, Label::Unknown(0o212));
+        assert_eq!(
+            table.get_for(EquipmentId::DADC, 0o212).unwrap().name,
+            "Vertical Speed"
+        );
+        assert_eq!(
+            table.get_for(EquipmentId::IRS, 0o212).unwrap().name,
+            "Inertial Vertical Speed"
+        );
+        // DADC: 11-bit field, 0x7F0 = -16 counts * 16 ft/min
+        assert_eq!(
+            dadc.to_physical_for(EquipmentId::DADC, &table),
+            Some(-256.0)
+        );
+        assert_eq!(irs.to_physical_for(EquipmentId::IRS, &table), Some(-16.0));
+
+        // No override: behaves like decode()
+        let gs = decode_for_equipment(0xE01F4050, EquipmentId::IRS, &table).unwrap();
+        assert_eq!(gs.label, Label::GroundSpeed);
+        assert_eq!(gs.to_physical_for(EquipmentId::IRS, &table), Some(250.0));
+    }
+
+    #[test]
+    fn test_sign_in_ssm() {
+        let mut table = LabelTable::new();
+        table.insert(
+            0o116,
+            LabelSpec::bnr("Cross Track Distance", "NM", 0.01, false, 18).with_sign_in_ssm(),
+        );
+        let word = |ssm| decode(encode(0o116, 0, 250, ssm).unwrap()).unwrap();
+
+        assert_eq!(word(0b00).to_physical_with(&table), Some(2.5));
+        assert_eq!(word(0b11).to_physical_with(&table), Some(-2.5));
+        assert_eq!(word(0b01).to_physical_with(&table), None);
+        assert_eq!(word(0b10).to_physical_with(&table), None);
+    }
+
+    #[test]
+    fn test_gray_coded_spec() {
+        let mut table = LabelTable::new();
+        table.insert(
+            0o117,
+            LabelSpec::bnr("Synchro Angle", "°", 0.5, true, 12).with_gray(),
+        );
+        // -3 counts in 12-bit two's complement, Gray-coded.
+        let field = 0xFFD;
+        let word = decode(encode(0o117, 0, field ^ (field >> 1), 3).unwrap()).unwrap();
+        assert_eq!(word.to_physical_with(&table), Some(-1.5));
+    }
+
+    #[test]
+    fn test_out_of_range_significant_bits_do_not_scale() {
+        let base = LabelSpec::bnr("Test", "", 1.0, true, 19);
+        for bits in [0, 20, 21, 32, 40] {
+            let spec = LabelSpec {
+                significant_bits: bits,
+                ..base
+            };
+            assert_eq!(spec.scale(0x7FFFF), None, "{bits} bits");
+
+            let mut table = LabelTable::new();
+            table.insert(0o117, spec);
+            let raw = encode(0o117, 0, 1, 3).unwrap();
+            assert!(crate::validate(raw, &table).is_empty());
+        }
+
+        let wide = base.with_sdi_as_data();
+        assert_eq!(wide.scale(0x1F_FFFF), Some(-1.0));
+        for bits in [0, 22, 32] {
+            let spec = LabelSpec {
+                significant_bits: bits,
+                ..wide
+            };
+            assert_eq!(spec.scale(0x1F_FFFF), None, "{bits} bits");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "significant_bits must be 1-21")]
+    fn test_bnr_rejects_zero_bits() {
+        LabelSpec::bnr("Test", "", 1.0, true, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "significant_bits must be 1-21")]
+    fn test_bnr_rejects_too_many_bits() {
+        LabelSpec::bnr("Test", "", 1.0, false, 22);
+    }
+
+    #[test]
+    fn test_heading_wraps_on_table_path() {
+        let table = LabelTable::builtin();
+        for label in [
+            Label::MagneticHeading,
+            Label::WindDirection,
+            Label::SelectedHeading,
+        ] {
+            // -1 count, i.e. -0.01°.
+            let raw = encode(label.raw(), 0, 0x7FFFF, 3).unwrap();
+            let word = decode(raw).unwrap();
+            let direct = word.to_physical().unwrap();
+            assert!((direct - 359.99).abs() < 1e-9, "{:?}", label);
+            assert_eq!(word.to_physical_with(&table), Some(direct));
+            assert_eq!(
+                crate::WordView::from_raw_checked(raw)
+                    .unwrap()
+                    .physical(&table),
+                Some(direct)
+            );
+        }
+        assert!(!table.get(Label::RollAngle.raw()).unwrap().heading);
+    }
+
+    #[test]
+    fn test_sdi_as_data_adds_two_bits() {
+        let lsb = 180.0 / 1048576.0; // 20 bits plus sign
+        let mut coarse = LabelTable::new();
+        coarse.insert(0o254, LabelSpec::bnr("Latitude", "°", lsb * 4.0, true, 19));
+        let mut fine = LabelTable::new();
+        fine.insert(
+            0o254,
+            LabelSpec::bnr("Latitude", "°", lsb, true, 21).with_sdi_as_data(),
+        );
+
+        // 45° plus three 21-bit counts: the extra counts live in the SDI bits.
+        let counts = 262_147u32;
+        let raw = encode(0o254, (counts & 3) as u8, counts >> 2, 3).unwrap();
+        let word = coarse.decode(raw).unwrap();
+        assert_eq!((word.sdi, word.data), (3, counts >> 2));
+        assert_eq!(word.to_physical_with(&coarse), Some(45.0));
+        let word = fine.decode(raw).unwrap();
+        assert_eq!((word.sdi, word.data), (0, counts));
+        assert_eq!(word.to_physical_with(&fine), Some(45.0 + 3.0 * lsb));
+        assert_eq!(
+            crate::WordView::from_raw_checked(raw)
+                .unwrap()
+                .physical(&fine),
+            Some(45.0 + 3.0 * lsb)
+        );
+
+        // The sign is the top bit of the 21-bit field.
+        let negative = counts.wrapping_neg() & 0x1F_FFFF;
+        let raw = encode(0o254, (negative & 3) as u8, negative >> 2, 3).unwrap();
+        let word = fine.decode(raw).unwrap();
+        assert_eq!(word.to_physical_with(&fine), Some(-45.0 - 3.0 * lsb));
+        assert_eq!(fine.decode(raw ^ 1 << 31), Err(ArincError::ParityMismatch));
+    }
+}
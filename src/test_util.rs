@@ -0,0 +1,73 @@
+//! Assertions for downstream test suites (`test-util` feature).
+
+use crate::{decode, encode};
+
+/// Assert that a word built from these fields decodes back to the same fields and
+/// re-encodes to the same word.
+///
+/// Intended for property tests over a crate's own label codes, e.g. every label in a
+/// [`LabelTable`](crate::LabelTable) with arbitrary data.
+///
+/// ```rust
+/// use arinc429::assert_roundtrip;
+///
+/// for data in [0, 1, 0x40000, 0x7FFFF] {
+///     assert_roundtrip(0o203, 2, data, 3);
+/// }
+/// ```
+///
+/// # Panics
+/// If any field is out of range for [`encode`], or if decoding or re-encoding the word
+/// does not reproduce the input. The message names the fields and the word.
+#[track_caller]
+pub fn assert_roundtrip(label: u8, sdi: u8, data: u32, ssm: u8) {
+    let fields = (label, sdi, data, ssm);
+    let word = encode(label, sdi, data, ssm).unwrap_or_else(|e| {
+        panic!(
+            "encode(label {:03o}, sdi {}, data {:#X}, ssm {}) failed: {}",
+            label, sdi, data, ssm, e
+        )
+    });
+    let decoded = decode(word).unwrap_or_else(|e| panic!("decode({:#010X}) failed: {}", word, e));
+
+    let got = (
+        decoded.label.raw(),
+        decoded.sdi,
+        decoded.data,
+        decoded.ssm.as_u8(),
+    );
+    assert_eq!(
+        got, fields,
+        "word {:#010X} decoded to (label, sdi, data, ssm) {:?}, expected {:?}",
+        word, got, fields
+    );
+
+    match decoded.to_word() {
+        Ok(reencoded) => assert_eq!(
+            reencoded, word,
+            "word {:#010X} re-encoded as {:#010X}",
+            word, reencoded
+        ),
+        Err(e) => panic!("re-encoding {:#010X} failed: {}", word, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip_all_labels() {
+        for label in 0..=255 {
+            for (sdi, data, ssm) in [(0, 0, 0), (3, 0x7FFFF, 3), (1, 0x2AAAA, 2)] {
+                assert_roundtrip(label, sdi, data, ssm);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "encode(label 012, sdi 4, data 0x0, ssm 0) failed")]
+    fn test_out_of_range_field_panics() {
+        assert_roundtrip(0o012, 4, 0, 0);
+    }
+}
@@ -0,0 +1,168 @@
+//! Bus speed and load estimates for label schedules.
+
+use core::time::Duration;
+
+use crate::{ArincError, ArincWord, Label};
+
+/// Minimum gap between consecutive words, in bit times.
+const INTER_WORD_GAP_BITS: u32 = 4;
+
+/// ARINC 429 bus speed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BitRate {
+    /// Low speed, 12.5 kbit/s
+    Low,
+    /// High speed, 100 kbit/s
+    High,
+}
+
+impl BitRate {
+    /// Nominal bit rate.
+    pub const fn bits_per_second(&self) -> u32 {
+        match self {
+            BitRate::Low => 12_500,
+            BitRate::High => 100_000,
+        }
+    }
+
+    /// Duration of one bit: 80 µs at low speed, 10 µs at high speed.
+    pub const fn bit_time(&self) -> Duration {
+        Duration::from_nanos(1_000_000_000 / self.bits_per_second() as u64)
+    }
+
+    /// Bus time taken by one word: its 32 bits plus the minimum 4-bit inter-word gap.
+    ///
+    /// This is the shortest possible spacing between consecutive word starts, so
+    /// `1 / word_time()` is the bus's maximum word rate.
+    pub const fn word_time(&self) -> Duration {
+        let bit = self.bit_time();
+        Duration::from_nanos(bit.as_nanos() as u64 * (32 + INTER_WORD_GAP_BITS) as u64)
+    }
+}
+
+/// On-wire duration of one word at `bitrate`, including the minimum inter-word gap:
+/// 360 µs at high speed and 2.88 ms at low speed.
+///
+/// Every word has the same duration; see [`BitRate::word_time`].
+pub const fn word_duration(bitrate: BitRate) -> Duration {
+    bitrate.word_time()
+}
+
+impl ArincWord {
+    /// Time this word occupies the bus at `bitrate`; see [`word_duration`].
+    pub const fn transmission_time(&self, bitrate: BitRate) -> Duration {
+        word_duration(bitrate)
+    }
+}
+
+/// Fraction of `bitrate`'s capacity used by a label schedule.
+///
+/// Each entry is a label and its transmit period; a `None` period uses the label's
+/// fastest standard rate (the minimum of [`Label::transmission_interval`]), giving a
+/// worst-case figure. Every word costs [`BitRate::word_time`].
+///
+/// The result is not clamped: a value above `1.0` means the schedule does not fit on
+/// the bus.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use arinc429::{bus_utilization, BitRate, Label};
+///
+/// let schedule = [(Label::GroundSpeed, Some(Duration::from_millis(10)))];
+/// let load = bus_utilization(&schedule, BitRate::Low).unwrap();
+/// assert!((load - 0.288).abs() < 1e-9);
+/// ```
+///
+/// # Errors
+/// [`ArincError::UnsupportedLabel`] for a `None` period on a label with no standard
+/// interval.
+///
+/// # Panics
+/// If an explicit period is zero.
+pub fn bus_utilization(
+    labels: &[(Label, Option<Duration>)],
+    bitrate: BitRate,
+) -> Result<f64, ArincError> {
+    let mut words_per_second = 0.0;
+    for &(label, period) in labels {
+        let period = match period {
+            Some(period) => period,
+            None => {
+                label
+                    .transmission_interval()
+                    .ok_or(ArincError::UnsupportedLabel(label))?
+                    .0
+            }
+        };
+        assert!(!period.is_zero(), "transmit period must be non-zero");
+        words_per_second += 1.0 / period.as_secs_f64();
+    }
+    Ok(words_per_second * bitrate.word_time().as_secs_f64())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Option<Duration> {
+        Some(Duration::from_millis(millis))
+    }
+
+    #[test]
+    fn test_word_times() {
+        assert_eq!(BitRate::High.bit_time() * 32, Duration::from_micros(320));
+        assert_eq!(BitRate::Low.bit_time() * 32, Duration::from_micros(2560));
+        assert_eq!(BitRate::High.word_time(), Duration::from_micros(360));
+        assert_eq!(BitRate::Low.word_time(), Duration::from_micros(2880));
+        assert_eq!(
+            BitRate::Low.word_time().as_nanos() * u128::from(BitRate::Low.bits_per_second()),
+            36 * 1_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_word_duration() {
+        assert_eq!(word_duration(BitRate::High), Duration::from_micros(360));
+        assert_eq!(word_duration(BitRate::Low), Duration::from_micros(2880));
+        let word = crate::decode(0xE01F4050).unwrap();
+        assert_eq!(
+            word.transmission_time(BitRate::Low),
+            word_duration(BitRate::Low)
+        );
+    }
+
+    #[test]
+    fn test_small_high_speed_schedule() {
+        // 20 + 10 + 40 = 70 words/s at 36 bits each over 100 kbit/s.
+        let schedule = [
+            (Label::RollAngle, ms(50)),
+            (Label::PressureAltitude, ms(100)),
+            (Label::GroundSpeed, ms(25)),
+        ];
+        let load = bus_utilization(&schedule, BitRate::High).unwrap();
+        assert!((load - 0.0252).abs() < 1e-12);
+        let low = bus_utilization(&schedule, BitRate::Low).unwrap();
+        assert!((low - 8.0 * load).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_default_periods() {
+        let (fastest, _) = Label::Tat.transmission_interval().unwrap();
+        assert_eq!(
+            bus_utilization(&[(Label::Tat, None)], BitRate::High),
+            bus_utilization(&[(Label::Tat, Some(fastest))], BitRate::High)
+        );
+        assert_eq!(
+            bus_utilization(&[(Label::Unknown(0o377), None)], BitRate::High),
+            Err(ArincError::UnsupportedLabel(Label::Unknown(0o377)))
+        );
+        assert_eq!(bus_utilization(&[], BitRate::High), Ok(0.0));
+    }
+
+    #[test]
+    fn test_overloaded_schedule_exceeds_one() {
+        let schedule = [(Label::GroundSpeed, Some(Duration::from_micros(100)))];
+        assert!(bus_utilization(&schedule, BitRate::High).unwrap() > 1.0);
+    }
+}
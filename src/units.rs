@@ -0,0 +1,144 @@
+//! Conversion of decoded values out of each label's native unit.
+
+use crate::Label;
+
+/// Display unit for [`ArincWord::to_physical_in`](crate::ArincWord::to_physical_in).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Unit {
+    /// Feet
+    Feet,
+    /// Meters
+    Meters,
+    /// Knots
+    Knots,
+    /// Kilometres per hour
+    KmH,
+    /// Meters per second
+    Ms,
+    /// Degrees Celsius
+    Celsius,
+    /// Degrees Fahrenheit
+    Fahrenheit,
+    /// Kilograms
+    Kilograms,
+    /// Pounds
+    Pounds,
+    /// Kilograms per hour
+    KgPerHour,
+    /// Pounds per hour
+    LbPerHour,
+}
+
+/// One avoirdupois pound, in kilograms.
+const POUND: f64 = 0.453_592_37;
+
+#[derive(PartialEq)]
+enum Quantity {
+    Length,
+    Speed,
+    Temperature,
+    Mass,
+    MassFlow,
+}
+
+impl Unit {
+    /// Quantity measured and the size of one unit in SI terms (meters, m/s, °C, kg or
+    /// kg/s).
+    fn si(self) -> (Quantity, f64) {
+        match self {
+            Unit::Feet => (Quantity::Length, 0.3048),
+            Unit::Meters => (Quantity::Length, 1.0),
+            Unit::Knots => (Quantity::Speed, 1852.0 / 3600.0),
+            Unit::KmH => (Quantity::Speed, 1000.0 / 3600.0),
+            Unit::Ms => (Quantity::Speed, 1.0),
+            Unit::Celsius | Unit::Fahrenheit => (Quantity::Temperature, 1.0),
+            Unit::Kilograms => (Quantity::Mass, 1.0),
+            Unit::Pounds => (Quantity::Mass, POUND),
+            Unit::KgPerHour => (Quantity::MassFlow, 1.0 / 3600.0),
+            Unit::LbPerHour => (Quantity::MassFlow, POUND / 3600.0),
+        }
+    }
+}
+
+/// Quantity and SI size of `label`'s native unit, or `None` if it is not convertible.
+fn native(label: Label) -> Option<(Quantity, f64)> {
+    Some(match label.units() {
+        "feet" => Unit::Feet.si(),
+        "NM" => (Quantity::Length, 1852.0),
+        "knots" => Unit::Knots.si(),
+        "ft/min" => (Quantity::Speed, 0.3048 / 60.0),
+        "°C" => Unit::Celsius.si(),
+        "lb" => Unit::Pounds.si(),
+        "lb/h" => Unit::LbPerHour.si(),
+        _ => return None,
+    })
+}
+
+/// Convert `value`, in `label`'s native unit, to `unit`.
+pub(crate) fn convert(label: Label, value: f64, unit: Unit) -> Option<f64> {
+    let (quantity, from) = native(label)?;
+    let (wanted, to) = unit.si();
+    if quantity != wanted {
+        return None;
+    }
+    Some(match unit {
+        Unit::Fahrenheit => value * 9.0 / 5.0 + 32.0,
+        _ => value * from / to,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(actual: Option<f64>, expected: f64) -> bool {
+        actual.is_some_and(|v| (v - expected).abs() < 1e-9)
+    }
+
+    #[test]
+    fn test_native_unit_is_identity() {
+        assert!(close(
+            convert(Label::PressureAltitude, 1234.0, Unit::Feet),
+            1234.0
+        ));
+        assert!(close(
+            convert(Label::GroundSpeed, 250.0, Unit::Knots),
+            250.0
+        ));
+        assert!(close(convert(Label::Tat, -12.5, Unit::Celsius), -12.5));
+        assert!(close(
+            convert(Label::FuelFlow, 2500.0, Unit::LbPerHour),
+            2500.0
+        ));
+    }
+
+    #[test]
+    fn test_cross_quantity_conversions() {
+        assert!(close(
+            convert(Label::DmeDistance, 1.0, Unit::Meters),
+            1852.0
+        ));
+        assert!(close(convert(Label::VerticalSpeed, 1000.0, Unit::Ms), 5.08));
+        assert!(close(convert(Label::Tat, -40.0, Unit::Fahrenheit), -40.0));
+        assert!(close(convert(Label::Egt, 100.0, Unit::Fahrenheit), 212.0));
+        assert!(close(
+            convert(Label::FuelQuantity, 1000.0, Unit::Kilograms),
+            453.59237
+        ));
+        assert!(close(
+            convert(Label::FuelFlow, 1000.0, Unit::KgPerHour),
+            453.59237
+        ));
+    }
+
+    #[test]
+    fn test_incompatible_units() {
+        assert_eq!(convert(Label::PressureAltitude, 1.0, Unit::Knots), None);
+        assert_eq!(convert(Label::Tat, 1.0, Unit::Meters), None);
+        assert_eq!(convert(Label::RollAngle, 1.0, Unit::Feet), None);
+        assert_eq!(convert(Label::Mach, 0.8, Unit::Knots), None);
+        assert_eq!(convert(Label::FuelFlow, 1.0, Unit::Kilograms), None);
+        assert_eq!(convert(Label::Unknown(0o377), 1.0, Unit::Feet), None);
+    }
+}
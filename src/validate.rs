@@ -0,0 +1,137 @@
+//! Non-fatal plausibility checks for bus monitoring.
+
+use alloc::vec::Vec;
+
+use crate::{decode_unchecked, field_mask, unpack_as, BcdSsm, DataType, LabelTable, Ssm};
+
+/// An anomaly found by [`validate`]. None of these prevent the word from being decoded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ValidationWarning {
+    /// The word fails the odd parity check
+    ParityMismatch,
+    /// The label code has no definition in the table
+    UnknownLabel(u8),
+    /// The SSM reports anything other than valid data (raw SSM bits)
+    SsmNotNormal(u8),
+    /// The physical value lies outside the label's range
+    ValueOutOfRange { value: f64, min: f64, max: f64 },
+    /// Data field bits above the label's significant bits are set (mask over the data field)
+    ReservedBitsSet(u32),
+}
+
+/// Decode `word` and report everything that looks suspect about it.
+///
+/// Unlike [`decode`](crate::decode) this never fails: parity errors, undefined labels,
+/// non-normal SSM, out-of-range values and stray bits in the unused part of a BNR data
+/// field are all collected as warnings. An empty vector means the word looks healthy.
+///
+/// ```rust
+/// use arinc429::{encode, validate, LabelTable, ValidationWarning};
+///
+/// let table = LabelTable::builtin();
+/// assert!(validate(encode(0o012, 0, 2000, 3).unwrap(), &table).is_empty());
+/// assert_eq!(
+///     validate(encode(0o012, 0, 2000, 1).unwrap(), &table),
+///     vec![ValidationWarning::SsmNotNormal(1)]
+/// );
+/// ```
+pub fn validate(word: u32, table: &LabelTable) -> Vec<ValidationWarning> {
+    let mut warnings = Vec::new();
+    let (decoded, parity_ok) = decode_unchecked(word);
+    if !parity_ok {
+        warnings.push(ValidationWarning::ParityMismatch);
+    }
+
+    let code = decoded.label.raw();
+    let Some(spec) = table.get(code) else {
+        warnings.push(ValidationWarning::UnknownLabel(code));
+        return warnings;
+    };
+    let decoded = unpack_as(word, spec.sdi_is_data);
+
+    let ssm_ok = match spec.data_type {
+        DataType::Bcd => matches!(
+            BcdSsm::from_u8(decoded.ssm.as_u8()),
+            BcdSsm::Plus | BcdSsm::Minus
+        ),
+        DataType::Bnr => spec.ssm_valid(decoded.ssm),
+        DataType::Discrete => decoded.ssm == Ssm::NormalOperation,
+    };
+    if !ssm_ok {
+        warnings.push(ValidationWarning::SsmNotNormal(decoded.ssm.as_u8()));
+    }
+
+    if spec.data_type == DataType::Bnr && spec.bits_valid() {
+        let reserved = decoded.data & !field_mask(spec.significant_bits);
+        if reserved != 0 {
+            warnings.push(ValidationWarning::ReservedBitsSet(reserved));
+        }
+
+        if let (Some(value), Some((min, max))) = (spec.value(&decoded), decoded.label.range()) {
+            if !(min..=max).contains(&value) {
+                warnings.push(ValidationWarning::ValueOutOfRange { value, min, max });
+            }
+        }
+    }
+
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{encode, LabelSpec};
+
+    #[test]
+    fn test_healthy_word_has_no_warnings() {
+        let table = LabelTable::builtin();
+        let word = encode(0o212, 0, 0x7FF, 3).unwrap();
+        assert!(validate(word, &table).is_empty());
+    }
+
+    #[test]
+    fn test_collects_multiple_warnings() {
+        let table = LabelTable::builtin();
+        // Vertical speed uses 11 bits; bit 12 of the data field is unused.
+        let word = encode(0o212, 0, 1 << 12, 0).unwrap() ^ (1 << 31);
+        assert_eq!(
+            validate(word, &table),
+            vec![
+                ValidationWarning::ParityMismatch,
+                ValidationWarning::SsmNotNormal(0),
+                ValidationWarning::ReservedBitsSet(1 << 12),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_label() {
+        let word = encode(0o377, 0, 0, 3).unwrap();
+        assert_eq!(
+            validate(word, &LabelTable::new()),
+            vec![ValidationWarning::UnknownLabel(0o377)]
+        );
+    }
+
+    #[test]
+    fn test_bcd_plus_is_normal() {
+        let table = LabelTable::builtin();
+        assert!(validate(encode(0o260, 0, 0, 0).unwrap(), &table).is_empty());
+        assert_eq!(
+            validate(encode(0o260, 0, 0, 1).unwrap(), &table),
+            vec![ValidationWarning::SsmNotNormal(1)]
+        );
+    }
+
+    #[test]
+    fn test_table_spec_is_used() {
+        let mut table = LabelTable::new();
+        table.insert(0o377, LabelSpec::bnr("Custom", "", 1.0, false, 8));
+        let word = encode(0o377, 0, 0x1FF, 3).unwrap();
+        assert_eq!(
+            validate(word, &table),
+            vec![ValidationWarning::ReservedBitsSet(0x100)]
+        );
+    }
+}
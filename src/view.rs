@@ -0,0 +1,138 @@
+//! Lazy field access to a raw word.
+
+use crate::{peek_label, peek_sdi, ArincError, Label, Parity, Ssm, SDI_AS_DATA};
+#[cfg(feature = "alloc")]
+use crate::{unpack_as, LabelTable};
+
+/// A parity-checked word whose fields are extracted only when asked for.
+///
+/// A lighter alternative to [`decode`](crate::decode) when only a few fields of each word
+/// are needed, e.g. filtering a large capture by label before decoding the words kept.
+///
+/// ```rust
+/// use arinc429::{Label, WordView};
+///
+/// let view = WordView::from_raw_checked(0xE01F4050).unwrap();
+/// assert_eq!(view.label(), Label::GroundSpeed);
+/// assert_eq!(view.data(), 2000);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WordView(u32);
+
+impl WordView {
+    /// View `word` after checking its odd parity.
+    ///
+    /// # Errors
+    /// [`ArincError::ParityMismatch`] if the parity is wrong.
+    #[inline]
+    pub const fn from_raw_checked(word: u32) -> Result<Self, ArincError> {
+        if Parity::Odd.is_valid(word) {
+            Ok(Self(word))
+        } else {
+            Err(ArincError::ParityMismatch)
+        }
+    }
+
+    /// The raw word.
+    #[inline]
+    pub const fn word(&self) -> u32 {
+        self.0
+    }
+
+    /// Label of the word.
+    #[inline]
+    pub const fn label(&self) -> Label {
+        Label::from_u8(peek_label(self.0))
+    }
+
+    /// Source/Destination Identifier (0–3), or 0 for labels that use bits 9–10 as data,
+    /// as in [`ArincWord::sdi`](crate::ArincWord::sdi).
+    #[inline]
+    pub const fn sdi(&self) -> u8 {
+        if SDI_AS_DATA[peek_label(self.0) as usize] {
+            0
+        } else {
+            peek_sdi(self.0)
+        }
+    }
+
+    /// Sign/Status Matrix.
+    #[inline]
+    pub const fn ssm(&self) -> Ssm {
+        Ssm::from_u8(((self.0 >> 29) & 0x3) as u8)
+    }
+
+    /// Data field, 21 bits wide for labels that use bits 9–10 as data, as in
+    /// [`ArincWord::data`](crate::ArincWord::data).
+    #[inline]
+    pub const fn data(&self) -> u32 {
+        if SDI_AS_DATA[peek_label(self.0) as usize] {
+            (self.0 >> 8) & 0x1F_FFFF
+        } else {
+            (self.0 >> 10) & 0x7FFFF
+        }
+    }
+
+    /// Physical value of the word as defined in `table`, like
+    /// [`ArincWord::to_physical_with`](crate::ArincWord::to_physical_with) on a word from
+    /// [`LabelTable::decode`].
+    ///
+    /// ```rust
+    /// use arinc429::{LabelTable, WordView};
+    ///
+    /// let view = WordView::from_raw_checked(0xE01F4050).unwrap();
+    /// assert_eq!(view.physical(&LabelTable::builtin()), Some(250.0));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn physical(&self, table: &LabelTable) -> Option<f64> {
+        let spec = table.get(peek_label(self.0))?;
+        spec.physical(&unpack_as(self.0, spec.sdi_is_data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, encode};
+
+    #[test]
+    fn test_view_matches_decode() {
+        for word in [
+            encode(0o012, 0, 2000, 3).unwrap(),
+            encode(0o203, 2, 0x7FC18, 0).unwrap(),
+            encode(0o377, 3, 0x12345, 1).unwrap(),
+        ] {
+            let view = WordView::from_raw_checked(word).unwrap();
+            let decoded = decode(word).unwrap();
+            assert_eq!(view.word(), word);
+            assert_eq!(view.label(), decoded.label);
+            assert_eq!(view.sdi(), decoded.sdi);
+            assert_eq!(view.ssm(), decoded.ssm);
+            assert_eq!(view.data(), decoded.data);
+        }
+        assert_eq!(
+            WordView::from_raw_checked(encode(0o012, 0, 2000, 3).unwrap() ^ 1),
+            Err(ArincError::ParityMismatch)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_view_physical() {
+        let table = LabelTable::builtin();
+        let view = |word| WordView::from_raw_checked(word).unwrap();
+        assert_eq!(
+            view(encode(0o203, 0, 0x7FC18, 3).unwrap()).physical(&table),
+            Some(-1000.0)
+        );
+        // Invalid status and labels missing from the table have no value.
+        assert_eq!(
+            view(encode(0o203, 0, 1000, 1).unwrap()).physical(&table),
+            None
+        );
+        assert_eq!(
+            view(encode(0o100, 0, 1000, 3).unwrap()).physical(&table),
+            None
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! Block file transfer framing in the style of the ARINC 429 Williamsburg protocol.
+//!
+//! A payload is sent on a single label as the sequence
+//! `SOT, word count, data..., EOT`. Every word is a normal odd-parity ARINC 429 word
+//! (SDI 0, SSM Normal Operation) whose 19-bit data field is laid out as:
+//!
+//! | Data bits | ARINC bits | Content                                  |
+//! |-----------|------------|------------------------------------------|
+//! | 16–18     | 27–29      | Word type (SOT, count, data, EOT)        |
+//! | 0–15      | 11–26      | Value: byte count, word count or 2 bytes |
+//!
+//! Data words carry two payload bytes, the first in bits 8–15. An odd-length payload is
+//! padded with a trailing zero byte; the SOT byte count lets the receiver drop it.
+
+use alloc::vec::Vec;
+
+use crate::{decode, encode, ArincError, Ssm};
+
+const TYPE_SOT: u32 = 0b001;
+const TYPE_COUNT: u32 = 0b010;
+const TYPE_DATA: u32 = 0b011;
+const TYPE_EOT: u32 = 0b100;
+
+/// Segmentation and reassembly of file-transfer word sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileTransfer;
+
+impl FileTransfer {
+    /// Split `payload` into the SOT/count/data/EOT word sequence on `label`.
+    ///
+    /// # Panics
+    /// If `payload` is longer than 65535 bytes (the SOT byte count is 16 bits).
+    pub fn segment(label: u8, payload: &[u8]) -> Vec<u32> {
+        assert!(
+            payload.len() <= 0xFFFF,
+            "payload of {} bytes exceeds the 65535-byte transfer limit",
+            payload.len()
+        );
+
+        let chunks = payload.chunks(2);
+        let mut words = Vec::with_capacity(chunks.len() + 3);
+        words.push(control(label, TYPE_SOT, payload.len() as u32));
+        words.push(control(label, TYPE_COUNT, chunks.len() as u32));
+        for chunk in chunks {
+            let high = u32::from(chunk[0]);
+            let low = chunk.get(1).copied().map_or(0, u32::from);
+            words.push(control(label, TYPE_DATA, (high << 8) | low));
+        }
+        words.push(control(label, TYPE_EOT, 0));
+        words
+    }
+
+    /// Validate a word sequence produced by [`segment`](Self::segment) and return the payload.
+    ///
+    /// Returns [`ArincError::ParityMismatch`] for corrupted words and
+    /// [`ArincError::InvalidTransfer`] if the framing is inconsistent.
+    pub fn reassemble(words: &[u32]) -> Result<Vec<u8>, ArincError> {
+        let fields = words
+            .iter()
+            .map(|&word| decode(word).map(|d| (d.label, d.data >> 16, d.data & 0xFFFF)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (first, rest) = fields
+            .split_first()
+            .ok_or(ArincError::InvalidTransfer("empty sequence"))?;
+        let (label, kind, byte_count) = *first;
+        if kind != TYPE_SOT {
+            return Err(ArincError::InvalidTransfer("missing start of transmission"));
+        }
+        if fields.iter().any(|&(l, _, _)| l != label) {
+            return Err(ArincError::InvalidTransfer("label changed mid-transfer"));
+        }
+
+        let word_count = match rest.first() {
+            Some(&(_, TYPE_COUNT, count)) => count as usize,
+            _ => return Err(ArincError::InvalidTransfer("missing word count")),
+        };
+        match rest.last() {
+            Some(&(_, TYPE_EOT, _)) if rest.len() >= 2 => {}
+            _ => return Err(ArincError::InvalidTransfer("missing end of transmission")),
+        }
+
+        let data = &rest[1..rest.len() - 1];
+        if data.len() != word_count || word_count != (byte_count as usize).div_ceil(2) {
+            return Err(ArincError::InvalidTransfer("word count mismatch"));
+        }
+
+        let mut payload = Vec::with_capacity(word_count * 2);
+        for &(_, kind, value) in data {
+            if kind != TYPE_DATA {
+                return Err(ArincError::InvalidTransfer("unexpected control word"));
+            }
+            payload.extend_from_slice(&[(value >> 8) as u8, value as u8]);
+        }
+        payload.truncate(byte_count as usize);
+        Ok(payload)
+    }
+}
+
+/// Build one framing word; all fields are in range by construction.
+fn control(label: u8, kind: u32, value: u32) -> u32 {
+    encode(label, 0, (kind << 16) | value, Ssm::NormalOperation.as_u8())
+        .expect("framing fields always fit in the data field")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip_aligned() {
+        let payload = b"ARINC429";
+        let words = FileTransfer::segment(0o377, payload);
+        assert_eq!(words.len(), 3 + 4);
+        assert_eq!(FileTransfer::reassemble(&words).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_roundtrip_unaligned() {
+        let payload = b"odd";
+        let words = FileTransfer::segment(0o377, payload);
+        assert_eq!(words.len(), 3 + 2);
+        assert_eq!(FileTransfer::reassemble(&words).unwrap(), payload);
+
+        let empty = FileTransfer::segment(0o377, &[]);
+        assert_eq!(FileTransfer::reassemble(&empty).unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_rejects_bad_sequences() {
+        let words = FileTransfer::segment(0o377, b"hello");
+
+        assert_eq!(
+            FileTransfer::reassemble(&words[..words.len() - 1]),
+            Err(ArincError::InvalidTransfer("missing end of transmission"))
+        );
+        assert_eq!(
+            FileTransfer::reassemble(&words[1..]),
+            Err(ArincError::InvalidTransfer("missing start of transmission"))
+        );
+
+        let mut dropped = words.clone();
+        dropped.remove(2);
+        assert_eq!(
+            FileTransfer::reassemble(&dropped),
+            Err(ArincError::InvalidTransfer("word count mismatch"))
+        );
+
+        let mut corrupted = words.clone();
+        corrupted[3] ^= 1 << 12;
+        assert_eq!(
+            FileTransfer::reassemble(&corrupted),
+            Err(ArincError::ParityMismatch)
+        );
+    }
+}
@@ -0,0 +1,61 @@
+//! Runs the `arinc_decoder` binary end to end.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use serde_json::Value;
+
+fn run_decoder(input: &str) -> Vec<Value> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_arinc_decoder"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("decoder should start");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_json_array_input() {
+    let words = run_decoder(r#"["E01F4050", "6222E0C1"]"#);
+    assert_eq!(words.len(), 2);
+    assert_eq!(words[0]["word"], "E01F4050");
+    assert_eq!(words[0]["label"], "012");
+    assert_eq!(words[0]["name"], "Ground Speed");
+    assert_eq!(words[0]["sdi"], 0);
+    assert_eq!(words[0]["ssm"], "Normal Operation");
+    assert_eq!(words[0]["value"], 250.0);
+    assert_eq!(words[1]["label"], "203");
+    assert_eq!(words[1]["value"], 35000.0);
+}
+
+#[test]
+fn test_line_delimited_input() {
+    let words = run_decoder("E01F4050\n\n  6222E0C1  \n");
+    assert_eq!(words.len(), 2);
+    assert_eq!(words[0]["value"], 250.0);
+    assert_eq!(words[1]["word"], "6222E0C1");
+    assert_eq!(words[1]["value"], 35000.0);
+}
+
+#[test]
+fn test_parity_error_does_not_abort_batch() {
+    // E01F4050 with the parity bit flipped, between two good words.
+    let words = run_decoder("E01F4050\n601F4050\n6222E0C1\n");
+    assert_eq!(words.len(), 3);
+    assert!(words[1]["error"].is_string());
+    assert!(words[1].get("label").is_none());
+    assert!(words[0].get("error").is_none());
+    assert_eq!(words[2]["value"], 35000.0);
+}
@@ -0,0 +1,57 @@
+//! Runs the `arinc_encoder` binary end to end.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn run_encoder(args: &[&str], input: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_arinc_encoder"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("encoder should start");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_jsonl_prints_one_word_per_line() {
+    // Two supported labels, one unsupported name and one out-of-range value.
+    let input = r#"{"GroundSpeed": 2000, "Mach": 800, "Flaps": 3, "Tat": 1000000}"#;
+    let stdout = run_encoder(&["--jsonl"], input);
+
+    let lines: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    let ground_speed = lines.iter().find(|l| l["name"] == "GroundSpeed").unwrap();
+    assert_eq!(ground_speed["word"], "E01F4050");
+    assert!(lines.iter().any(|l| l["name"] == "Mach"));
+}
+
+#[test]
+fn test_single_object_by_default() {
+    let stdout = run_encoder(&[], r#"{"GroundSpeed": 2000, "Mach": 800}"#);
+    assert_eq!(stdout.lines().count(), 1);
+    let words: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(words["GroundSpeed"], "E01F4050");
+}
+
+#[test]
+fn test_csv_input() {
+    let input = "name,value\nGroundSpeed,2000\nnot a row\nMach,abc\n\nTat,-200\n";
+    let stdout = run_encoder(&["--csv"], input);
+    let words: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(words.as_object().unwrap().len(), 2);
+    assert_eq!(words["GroundSpeed"], "E01F4050");
+    assert_eq!(words["Tat"], "7FFCE091");
+}
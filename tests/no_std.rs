@@ -0,0 +1,42 @@
+//! Exercises the core (allocation-free) API from a `#![no_std]` crate.
+//!
+//! Build with `cargo test --no-default-features --test no_std` to check the crate
+//! links without `std` or `alloc`.
+
+#![no_std]
+
+extern crate std;
+
+use arinc429::{decode, encode, encode_physical, ArincWord, Label, Ssm};
+
+#[test]
+fn test_encode_decode_without_std() {
+    let word = encode(0o012, 0, 2000, 3).unwrap();
+    assert_eq!(word, 0xE01F4050);
+
+    let decoded = decode(word).unwrap();
+    assert_eq!(decoded.label, Label::GroundSpeed);
+    assert_eq!(decoded.to_physical(), Some(250.0));
+}
+
+#[test]
+fn test_physical_roundtrip_without_std() {
+    let word = encode_physical(Label::RollAngle, -2.0, 0, Ssm::NormalOperation).unwrap();
+    assert_eq!(decode(word).unwrap().to_physical(), Some(-2.0));
+}
+
+#[test]
+fn test_octal_and_bcd_without_alloc() {
+    let mut buf = [0u8; 3];
+    assert_eq!(Label::PressureAltitude.octal_to(&mut buf), "203");
+
+    let time = ArincWord {
+        label: Label::UtcTime,
+        sdi: 0,
+        data: arinc429::encode_bcd_time(12, 34, 56).unwrap(),
+        ssm: Ssm::NormalOperation,
+    }
+    .to_time()
+    .unwrap();
+    assert_eq!((time.hour, time.minute, time.second), (12, 34, 56));
+}